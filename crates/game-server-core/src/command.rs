@@ -1,12 +1,24 @@
 use mavuika_message::output::ClientOutput;
 use mavuika_persistence::player_information::PlayerInformation;
 use mavuika_proto::PacketHead;
+use tokio::sync::oneshot;
 
 pub enum LogicCommand {
     CreateWorld {
-        player_information: PlayerInformation,
+        player_information: Box<PlayerInformation>,
+        session_id: u32,
+        // PlayerLoginRsp is sent with it
+        login_head: PacketHead,
         output: ClientOutput,
     },
+    // Replies with output back if there's no world to resume
+    ResumeWorld {
+        uid: u32,
+        session_id: u32,
+        login_head: PacketHead,
+        output: ClientOutput,
+        result_tx: oneshot::Sender<Result<(), ClientOutput>>,
+    },
     ClientInput {
         head: PacketHead,
         cmd_id: u16,
@@ -14,4 +26,12 @@ pub enum LogicCommand {
         immediate_mode: bool,
     },
     WorldUpdate(u32),
+    DetachWorld {
+        uid: u32,
+        session_id: u32,
+    },
+    DestroyWorld {
+        uid: u32,
+        session_id: u32,
+    },
 }
@@ -111,10 +111,11 @@ pub fn sync_avatar_data(players: Res<Players>, out: Res<MessageOutput>) {
                             equip_guid_list: vec![a.weapon_guid],
                             skill_depot_id: a.skill_depot_id,
                             born_time: a.born_time,
-                            life_state: (a.cur_hp > 0.0)
-                                .then_some(LifeState::Alive)
-                                .unwrap_or(LifeState::Dead)
-                                as u32,
+                            life_state: if a.cur_hp > 0.0 {
+                                LifeState::Alive
+                            } else {
+                                LifeState::Dead
+                            } as u32,
                             avatar_type: 1, // TODO!
                             wearing_flycloak_id: a.wearing_flycloak_id,
                             costume_id: a.costume_id,
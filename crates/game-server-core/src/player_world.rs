@@ -1,6 +1,7 @@
 use std::{collections::HashMap, fs};
 
 use bevy_app::prelude::*;
+use bevy_ecs::system::RunSystemOnce;
 use mavuika_avatar::AvatarPlugin;
 use mavuika_combat::CombatPlugin;
 use mavuika_command::CommandPlugin;
@@ -15,17 +16,27 @@ use mavuika_message::{
 };
 use mavuika_pathfinding::PathfindingPlugin;
 use mavuika_persistence::{player_information::PlayerInformation, Players};
-use mavuika_proto::{PacketHead, PlayerLoginRsp};
-use mavuika_scene::{common::WorldOwnerUID, ScenePlugin};
+use mavuika_proto::{EnterType, PacketHead, PlayerLoginRsp};
+use mavuika_scene::{
+    common::{CurrentSceneID, WorldOwnerUID},
+    BeginEnterSceneEvent, ScenePlugin,
+};
 use mavuika_time::TimePlugin;
 use tracing::debug;
 
-use crate::player_data_sync::PlayerDataSyncPlugin;
+use crate::player_data_sync::{
+    sync_avatar_data, sync_open_state_map, sync_player_data, sync_player_store,
+    PlayerDataSyncPlugin,
+};
 
 pub struct PlayerWorld(App);
 
 impl PlayerWorld {
-    pub fn new(player_information: PlayerInformation, output: ClientOutput) -> Self {
+    pub fn new(
+        player_information: PlayerInformation,
+        login_head: PacketHead,
+        output: ClientOutput,
+    ) -> Self {
         let uid = player_information.uid;
 
         let message_out = MessageOutput::new(HashMap::from([(uid, output.clone())]));
@@ -65,7 +76,10 @@ impl PlayerWorld {
         app.update();
 
         output.push(
-            mavuika_proto::PacketHead::default(),
+            PacketHead {
+                client_sequence_id: login_head.client_sequence_id,
+                ..Default::default()
+            },
             PlayerLoginRsp::default(),
         );
 
@@ -74,6 +88,45 @@ impl PlayerWorld {
         Self(app)
     }
 
+    // Attaches new client to the existing world and re-syncs its state without re-initializing scene
+    pub fn reattach(&mut self, uid: u32, login_head: PacketHead, output: ClientOutput) {
+        let world = self.0.world_mut();
+        world
+            .resource_mut::<MessageOutput>()
+            .replace(uid, output.clone());
+
+        world.run_system_once(sync_player_data);
+        world.run_system_once(sync_player_store);
+        world.run_system_once(sync_open_state_map);
+        world.run_system_once(sync_avatar_data);
+
+        let scene_id = **world.resource::<CurrentSceneID>();
+        let position = world
+            .resource::<Players>()
+            .get(uid)
+            .world_position
+            .position
+            .into();
+
+        world.send_event(BeginEnterSceneEvent {
+            uid,
+            scene_id,
+            enter_type: EnterType::EnterSelf,
+            position,
+        });
+
+        self.0.update();
+        output.push(
+            PacketHead {
+                client_sequence_id: login_head.client_sequence_id,
+                ..Default::default()
+            },
+            PlayerLoginRsp::default(),
+        );
+
+        debug!("reattached player {uid} to world");
+    }
+
     pub fn add_packet(&mut self, head: PacketHead, cmd_id: u16, data: Box<[u8]>) {
         self.0
             .world_mut()
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, thread};
+use std::{collections::HashMap, thread, time::Duration};
 
 use crate::{command::LogicCommand, player_world::PlayerWorld};
 use common::time_util;
@@ -6,27 +6,63 @@ use mavuika_message::output::ClientOutput;
 use mavuika_persistence::player_information::PlayerInformation;
 use mavuika_proto::PacketHead;
 use std::sync::mpsc;
+use tokio::sync::oneshot;
+use tracing::{debug, error};
 
 #[derive(Clone)]
 pub struct LogicSimulator(mpsc::Sender<LogicCommand>);
 
 impl LogicSimulator {
-    pub fn spawn(save_data_tx: tokio::sync::mpsc::Sender<(u32, serde_json::Value)>) -> Self {
+    pub fn spawn(
+        save_data_tx: tokio::sync::mpsc::Sender<(u32, serde_json::Value)>,
+        resume_grace_period: Duration,
+    ) -> Self {
         let (tx, rx) = mpsc::channel();
 
-        thread::spawn(|| simulation_loop(rx, save_data_tx));
+        thread::spawn(move || simulation_loop(rx, save_data_tx, resume_grace_period.as_secs()));
         Self(tx)
     }
 
-    pub fn create_world(&self, player_information: PlayerInformation, output: ClientOutput) {
+    pub fn create_world(
+        &self,
+        player_information: PlayerInformation,
+        session_id: u32,
+        login_head: PacketHead,
+        output: ClientOutput,
+    ) {
         self.0
             .send(LogicCommand::CreateWorld {
-                player_information,
+                player_information: Box::new(player_information),
+                session_id,
+                login_head,
                 output,
             })
             .unwrap();
     }
 
+    // Hands player back the world it has been detached from.
+    // Output is given back if there's none, world has to be created from fetched data then.
+    pub async fn resume_world(
+        &self,
+        uid: u32,
+        session_id: u32,
+        login_head: PacketHead,
+        output: ClientOutput,
+    ) -> Result<(), ClientOutput> {
+        let (result_tx, result_rx) = oneshot::channel();
+        self.0
+            .send(LogicCommand::ResumeWorld {
+                uid,
+                session_id,
+                login_head,
+                output,
+                result_tx,
+            })
+            .unwrap();
+
+        result_rx.await.unwrap()
+    }
+
     pub fn add_client_packet(
         &self,
         head: PacketHead,
@@ -47,62 +83,249 @@ impl LogicSimulator {
     pub fn update_world(&self, uid: u32) {
         self.0.send(LogicCommand::WorldUpdate(uid)).unwrap();
     }
+
+    pub fn detach_world(&self, uid: u32, session_id: u32) {
+        self.0
+            .send(LogicCommand::DetachWorld { uid, session_id })
+            .unwrap();
+    }
+
+    pub fn destroy_world(&self, uid: u32, session_id: u32) {
+        self.0
+            .send(LogicCommand::DestroyWorld { uid, session_id })
+            .unwrap();
+    }
 }
 
 fn simulation_loop(
     command_receiver: mpsc::Receiver<LogicCommand>,
     save_data_tx: tokio::sync::mpsc::Sender<(u32, serde_json::Value)>,
+    resume_grace_period: u64,
 ) {
-    // client_player_uid -> world_owner_uid
-    let mut player_uid_map: HashMap<u32, u32> = HashMap::new();
-    let mut player_world_map: HashMap<u32, PlayerWorld> = HashMap::new();
-    let mut player_save_time_map: HashMap<u32, u64> = HashMap::new();
+    let mut worlds = WorldManager::new(save_data_tx);
+
+    loop {
+        let command = match command_receiver.recv_timeout(Duration::from_secs(1)) {
+            Ok(command) => command,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                worlds.destroy_expired(resume_grace_period);
+                continue;
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        };
 
-    while let Ok(command) = command_receiver.recv() {
         use LogicCommand::*;
         match command {
             CreateWorld {
                 player_information,
+                session_id,
+                login_head,
+                output,
+            } => worlds.create(*player_information, session_id, login_head, output),
+            ResumeWorld {
+                uid,
+                session_id,
+                login_head,
                 output,
+                result_tx,
             } => {
-                player_save_time_map.insert(player_information.uid, time_util::unix_timestamp());
-                player_uid_map.insert(player_information.uid, player_information.uid);
-                player_world_map.insert(
-                    player_information.uid,
-                    PlayerWorld::new(player_information, output),
-                );
+                let _ = result_tx.send(worlds.resume(uid, session_id, login_head, output));
             }
             ClientInput {
                 head,
                 cmd_id,
                 data,
                 immediate_mode,
-            } => {
-                let uid = head.user_id;
-                if let Some(world_owner_uid) = player_uid_map.get(&uid) {
-                    if let Some(world) = player_world_map.get_mut(world_owner_uid) {
-                        world.add_packet(head, cmd_id, data);
-                        if immediate_mode {
-                            world.update();
-                        }
-
-                        let save_time = player_save_time_map.get_mut(&uid).unwrap();
-                        let cur_time = time_util::unix_timestamp();
-                        if (cur_time - *save_time) >= 30 {
-                            *save_time = cur_time;
-                            let _ = save_data_tx
-                                .blocking_send((uid, world.serialize_player_information(uid)));
-                        }
-                    }
+            } => worlds.add_client_packet(head, cmd_id, data, immediate_mode),
+            WorldUpdate(uid) => {
+                if let Some(world) = worlds.get_mut(uid) {
+                    world.update();
                 }
             }
-            WorldUpdate(uid) => {
-                if let Some(world_owner_uid) = player_uid_map.get(&uid) {
-                    if let Some(world) = player_world_map.get_mut(world_owner_uid) {
-                        world.update();
-                    }
+            DetachWorld { uid, session_id } => worlds.detach(uid, session_id),
+            DestroyWorld { uid, session_id } => {
+                if worlds.is_current_session(uid, session_id) {
+                    worlds.destroy(uid);
                 }
             }
         }
+
+        worlds.destroy_expired(resume_grace_period);
+    }
+}
+
+struct WorldManager {
+    save_data_tx: tokio::sync::mpsc::Sender<(u32, serde_json::Value)>,
+    // client_player_uid -> world_owner_uid
+    player_uid_map: HashMap<u32, u32>,
+    player_world_map: HashMap<u32, PlayerWorld>,
+    player_save_time_map: HashMap<u32, u64>,
+    // player_uid -> gate session id
+    player_session_map: HashMap<u32, u32>,
+    // player_uid -> disconnect time, worlds are kept for resume until grace period passes
+    player_detach_time_map: HashMap<u32, u64>,
+}
+
+impl WorldManager {
+    fn new(save_data_tx: tokio::sync::mpsc::Sender<(u32, serde_json::Value)>) -> Self {
+        Self {
+            save_data_tx,
+            player_uid_map: HashMap::new(),
+            player_world_map: HashMap::new(),
+            player_save_time_map: HashMap::new(),
+            player_session_map: HashMap::new(),
+            player_detach_time_map: HashMap::new(),
+        }
+    }
+
+    fn get_mut(&mut self, uid: u32) -> Option<&mut PlayerWorld> {
+        self.player_uid_map
+            .get(&uid)
+            .and_then(|world_owner_uid| self.player_world_map.get_mut(world_owner_uid))
+    }
+
+    fn is_current_session(&self, uid: u32, session_id: u32) -> bool {
+        self.player_session_map.get(&uid) == Some(&session_id)
+    }
+
+    fn create(
+        &mut self,
+        mut player_information: PlayerInformation,
+        session_id: u32,
+        login_head: PacketHead,
+        output: ClientOutput,
+    ) {
+        let uid = player_information.uid;
+
+        // World may have been detached after login asked for a resume
+        let Err(output) = self.resume(uid, session_id, login_head.clone(), output) else {
+            return;
+        };
+
+        // Player is already logged in from another session, retire the old world.
+        // Its state is newer than the one fetched from database, so carry it over.
+        if let Some(mut old_world) = self.player_world_map.remove(&uid) {
+            debug!("retiring old world of player {uid}, relogin from session {session_id}");
+
+            let data = old_world.serialize_player_information(uid);
+            let _ = self.save_data_tx.blocking_send((uid, data.clone()));
+
+            match serde_json::from_value(data) {
+                Ok(data) => player_information = data,
+                Err(err) => error!("failed to deserialize player data of old world: {err}"),
+            }
+        }
+
+        self.player_session_map.insert(uid, session_id);
+        self.player_save_time_map
+            .insert(uid, time_util::unix_timestamp());
+        self.player_uid_map.insert(uid, uid);
+        self.player_world_map.insert(
+            uid,
+            PlayerWorld::new(player_information, login_head, output),
+        );
+    }
+
+    fn resume(
+        &mut self,
+        uid: u32,
+        session_id: u32,
+        login_head: PacketHead,
+        output: ClientOutput,
+    ) -> Result<(), ClientOutput> {
+        if self.player_detach_time_map.remove(&uid).is_some() {
+            if let Some(world) = self.player_world_map.get_mut(&uid) {
+                debug!("resuming world of player {uid}, session {session_id}");
+
+                world.reattach(uid, login_head, output);
+                self.player_session_map.insert(uid, session_id);
+                return Ok(());
+            }
+        }
+
+        Err(output)
+    }
+
+    fn add_client_packet(
+        &mut self,
+        head: PacketHead,
+        cmd_id: u16,
+        data: Box<[u8]>,
+        immediate_mode: bool,
+    ) {
+        let uid = head.user_id;
+        let Some(world_owner_uid) = self.player_uid_map.get(&uid) else {
+            return;
+        };
+
+        let Some(world) = self.player_world_map.get_mut(world_owner_uid) else {
+            return;
+        };
+
+        world.add_packet(head, cmd_id, data);
+        if immediate_mode {
+            world.update();
+        }
+
+        let save_time = self.player_save_time_map.get_mut(&uid).unwrap();
+        let cur_time = time_util::unix_timestamp();
+        if (cur_time - *save_time) >= 30 {
+            *save_time = cur_time;
+            let _ = self
+                .save_data_tx
+                .blocking_send((uid, world.serialize_player_information(uid)));
+        }
+    }
+
+    fn detach(&mut self, uid: u32, session_id: u32) {
+        // Player has already relogged in from another session
+        if !self.is_current_session(uid, session_id) {
+            return;
+        }
+
+        if let Some(world) = self.get_mut(uid) {
+            let data = world.serialize_player_information(uid);
+            let _ = self.save_data_tx.blocking_send((uid, data));
+
+            self.player_detach_time_map
+                .insert(uid, time_util::unix_timestamp());
+            debug!("player {uid} disconnected, world is kept for resume");
+        }
+    }
+
+    fn destroy_expired(&mut self, grace_period: u64) {
+        let cur_time = time_util::unix_timestamp();
+        let expired = self
+            .player_detach_time_map
+            .iter()
+            .filter(|(_, detach_time)| cur_time.saturating_sub(**detach_time) >= grace_period)
+            .map(|(uid, _)| *uid)
+            .collect::<Vec<_>>();
+
+        for uid in expired {
+            debug!("resume grace period of player {uid} has expired");
+            self.destroy(uid);
+        }
+    }
+
+    fn destroy(&mut self, uid: u32) {
+        self.player_detach_time_map.remove(&uid);
+        self.player_session_map.remove(&uid);
+        self.player_save_time_map.remove(&uid);
+
+        let Some(world_owner_uid) = self.player_uid_map.remove(&uid) else {
+            return;
+        };
+
+        if world_owner_uid != uid {
+            return;
+        }
+
+        // Dropping the world also drops its ClientOutput
+        if let Some(mut world) = self.player_world_map.remove(&world_owner_uid) {
+            let _ = self
+                .save_data_tx
+                .blocking_send((uid, world.serialize_player_information(uid)));
+        }
     }
 }
@@ -7,6 +7,7 @@ pub struct GameServerConfig {
     pub database: DatabaseSettings,
     pub service_listen_addr: String,
     pub gate_server_addr: String,
+    pub session_resume_timeout_secs: u64,
 }
 
 impl TomlConfig for GameServerConfig {
@@ -41,6 +41,18 @@ async fn db_work_loop(
 ) {
     loop {
         select! {
+            // Flush pending saves first, so relogging players don't fetch stale data
+            biased;
+
+            save_data = save_data_rx.recv() => {
+                if let Some((uid, data)) = save_data {
+                    if let Err(err) =
+                        sql_op::insert_or_update_player_data(&connection, uid as i32, data).await
+                    {
+                        error!("failed to save player data: {err}");
+                    }
+                }
+            }
             op = op_rx.recv() => {
                 if let Some(DbOperation::Fetch(uid, tx)) = op {
                     let result = match sql_op::select_player_data_by_uid(&connection, uid as i32).await
@@ -61,15 +73,6 @@ async fn db_work_loop(
                 let _ = tx.send(result);
                 }
             },
-            save_data = save_data_rx.recv() => {
-                if let Some((uid, data)) = save_data {
-                    if let Err(err) =
-                        sql_op::insert_or_update_player_data(&connection, uid as i32, data).await
-                    {
-                        error!("failed to save player data: {err}");
-                    }
-                }
-            }
         }
     }
 }
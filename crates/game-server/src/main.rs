@@ -1,4 +1,7 @@
-use std::sync::{LazyLock, OnceLock};
+use std::{
+    sync::{LazyLock, OnceLock},
+    time::Duration,
+};
 
 use anyhow::Result;
 use common::{logging, TomlConfig};
@@ -38,7 +41,10 @@ async fn main() -> Result<()> {
 
     let state = STATE.get_or_init(move || AppState {
         db_handle,
-        logic_simulator: LogicSimulator::spawn(save_data_tx),
+        logic_simulator: LogicSimulator::spawn(
+            save_data_tx,
+            Duration::from_secs(CONFIG.session_resume_timeout_secs),
+        ),
         gate_server_socket,
     });
 
@@ -1,7 +1,7 @@
 use mavuika_message::output::ClientOutput;
 use mavuika_proto::{
     raw_packet::{make_raw_packet, RawPacket},
-    CmdID, PacketHead, PlayerLoginReq, UnionCmdNotify,
+    CmdID, PacketHead, PlayerLoginReq, PlayerLogoutReq, PlayerLogoutReqReason, UnionCmdNotify,
 };
 use tokio::sync::mpsc;
 use tracing::{debug, error, warn};
@@ -31,13 +31,29 @@ pub async fn on_message(state: &'static AppState, data: Box<[u8]>) {
                 packet.head.user_session_id, packet.head.user_id
             );
 
-            player_login(
-                state,
-                packet.head.user_id,
-                packet.head.user_session_id,
-                packet.body,
-            )
-            .await;
+            player_login(state, packet.head, packet.body).await;
+        }
+        PlayerLogoutReq::CMD_ID => {
+            let Ok(packet) = packet.decode_as::<PlayerLogoutReq>() else {
+                warn!(
+                    "malformed logout request received, content: {}",
+                    hex::encode(&data)
+                );
+                return;
+            };
+
+            let (uid, session_id) = (packet.head.user_id, packet.head.user_session_id);
+            debug!(
+                "received player logout request, session id: {session_id}, player uid: {uid}, reason: {:?}",
+                packet.body.reason()
+            );
+
+            // Connection was lost, keep the world for a while so client can resume
+            if packet.body.reason() == PlayerLogoutReqReason::ReasonDisconnect {
+                state.logic_simulator.detach_world(uid, session_id);
+            } else {
+                state.logic_simulator.destroy_world(uid, session_id);
+            }
         }
         UnionCmdNotify::CMD_ID => {
             let Ok(packet) = packet.decode_as::<UnionCmdNotify>() else {
@@ -76,15 +92,25 @@ pub async fn on_message(state: &'static AppState, data: Box<[u8]>) {
     }
 }
 
-async fn player_login(
-    state: &'static AppState,
-    user_id: u32,
-    user_session_id: u32,
-    _request: PlayerLoginReq,
-) {
+async fn player_login(state: &'static AppState, head: PacketHead, _request: PlayerLoginReq) {
+    let (user_id, user_session_id) = (head.user_id, head.user_session_id);
     let (tx, rx) = mpsc::channel(32);
     tokio::spawn(packet_sink(state, user_id, user_session_id, rx));
 
+    // World kept after disconnect has newer state than database, so nothing is fetched then
+    let Err(output) = state
+        .logic_simulator
+        .resume_world(
+            user_id,
+            user_session_id,
+            head.clone(),
+            ClientOutput::new(tx),
+        )
+        .await
+    else {
+        return;
+    };
+
     let Some(player_data) = state.db_handle.fetch(user_id).await else {
         error!("failed to get player data, uid: {user_id}");
         return;
@@ -92,7 +118,7 @@ async fn player_login(
 
     state
         .logic_simulator
-        .create_world(player_data, ClientOutput::new(tx));
+        .create_world(player_data, user_session_id, head, output);
 }
 
 async fn packet_sink(
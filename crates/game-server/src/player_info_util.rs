@@ -54,7 +54,7 @@ pub fn create_default_player_information(uid: u32, nick_name: String) -> PlayerI
         AvatarTeamInformation {
             avatar_guid_list: DEFAULT_TEAM
                 .iter()
-                .map(|id| {
+                .filter_map(|id| {
                     player
                         .avatar_module
                         .avatar_map
@@ -62,7 +62,6 @@ pub fn create_default_player_information(uid: u32, nick_name: String) -> PlayerI
                         .find(|(_, av)| av.avatar_id == *id)
                         .map(|(guid, _)| *guid)
                 })
-                .flatten()
                 .collect(),
             name: String::new(),
         },
@@ -16,6 +16,7 @@ pub struct GateServerConfig {
 #[derive(Deserialize)]
 pub struct NetworkSettings {
     pub udp_host: String,
+    pub session_idle_timeout_secs: u64,
 }
 
 impl TomlConfig for GateServerConfig {
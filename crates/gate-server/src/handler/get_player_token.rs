@@ -43,6 +43,21 @@ fn gen_session_key(
     req: &GetPlayerTokenReq,
     rsp: &mut GetPlayerTokenRsp,
 ) -> Option<MhyXorpad> {
+    let keys = state.key_pair_map.get(&req.key_id)?;
+    let client_rand_key = decrypt_client_rand_key(state, req)?;
+    let server_rand_key = rand::thread_rng().next_u64();
+
+    rsp.server_rand_key = rbase64::encode(&keys.encrypt(&server_rand_key.to_be_bytes()));
+    rsp.sign = rbase64::encode(&keys.sign(&server_rand_key.to_be_bytes()));
+
+    Some(MhyXorpad::new::<byteorder::BE>(
+        client_rand_key ^ server_rand_key,
+        XorpadGenerationMethod::ReseedWithSkip,
+    ))
+}
+
+// Only a request decoded with the right client version carries a key that decrypts
+pub fn decrypt_client_rand_key(state: &AppState, req: &GetPlayerTokenReq) -> Option<u64> {
     if !state
         .region_config
         .allowed_key_id_list
@@ -79,20 +94,10 @@ fn gen_session_key(
         return None;
     };
 
-    let client_rand_key = u64::from_be_bytes(
+    Some(u64::from_be_bytes(
         client_rand_key
             .try_into()
             .inspect_err(|_| debug!("client_rand_key is not uint64"))
             .ok()?,
-    );
-
-    let server_rand_key = rand::thread_rng().next_u64();
-
-    rsp.server_rand_key = rbase64::encode(&keys.encrypt(&server_rand_key.to_be_bytes()));
-    rsp.sign = rbase64::encode(&keys.sign(&server_rand_key.to_be_bytes()));
-
-    Some(MhyXorpad::new::<byteorder::BE>(
-        client_rand_key ^ server_rand_key,
-        XorpadGenerationMethod::ReseedWithSkip,
     ))
 }
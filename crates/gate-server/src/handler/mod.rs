@@ -4,16 +4,17 @@ pub mod server_message_handler;
 
 use std::sync::{Arc, OnceLock};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use mavuika_encryption::xor::MhyXorpad;
 use mavuika_proto::{
-    packet::{self, NetPacket},
+    packet::{self, ClientProtocol, NetPacket},
     raw_packet::{make_raw_packet, RawPacket},
-    CmdID, GetPlayerTokenReq, PacketHead, PingReq, PingRsp, Protobuf, Retcode, UnionCmdNotify,
+    CmdID, ENetReason, GetPlayerTokenReq, GetPlayerTokenRsp, PacketHead, PingReq, PingRsp,
+    PlayerLogoutReq, PlayerLogoutReqReason, Protobuf, Retcode, UnionCmdNotify,
 };
-use protocol_util::convert_union_cmd_notify_data;
+use protocol_util::{convert_invocations_client_to_normal, convert_union_cmd_notify_data};
 use tokio::sync::mpsc;
-use tracing::{debug, warn};
+use tracing::{debug, error, warn};
 
 use crate::{net::Connection, util, AppState};
 
@@ -31,6 +32,7 @@ pub struct Session {
     pub account_uid: OnceLock<String>,
     pub player_uid: OnceLock<u32>,
     pub xorpad: OnceLock<MhyXorpad>,
+    pub protocol: OnceLock<&'static ClientProtocol>,
 }
 
 impl PacketHandler {
@@ -65,11 +67,14 @@ async fn packet_handler_loop(mut rx: mpsc::UnboundedReceiver<InputItem>, state:
                         account_uid: OnceLock::new(),
                         player_uid: OnceLock::new(),
                         xorpad: OnceLock::new(),
+                        protocol: OnceLock::new(),
                     }),
                 );
             }
             InputItem::DropConnection(id) => {
-                state.sessions.remove(&id);
+                if let Some((_, session)) = state.sessions.remove(&id) {
+                    notify_player_logout(state, &session).await;
+                }
             }
             InputItem::Packet(id, buf) => {
                 if let Some(session) = state.sessions.get(&id) {
@@ -82,6 +87,103 @@ async fn packet_handler_loop(mut rx: mpsc::UnboundedReceiver<InputItem>, state:
     }
 }
 
+// Client version is told by the initial GetPlayerTokenReq: its client_rand_key only decrypts
+// if the request was decoded with the version client speaks. Bound versions sharing the cmd_id
+// of GetPlayerTokenReq are told apart this way, the first of them is taken if that fails.
+fn negotiate_protocol(
+    state: &'static AppState,
+    session: &Session,
+    cmd_id: u16,
+    body: &[u8],
+) -> Result<&'static ClientProtocol> {
+    let candidates = state
+        .region_config
+        .bind_version_list
+        .iter()
+        .filter_map(|version| packet::client_protocol(version))
+        .filter_map(|protocol| match (protocol.client_to_normal)(cmd_id, body) {
+            Ok((GetPlayerTokenReq::CMD_ID, body)) => {
+                Some((protocol, GetPlayerTokenReq::decode(body.as_ref()).ok()?))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let protocol = match candidates.as_slice() {
+        [] => {
+            return Err(anyhow!(
+                "no client protocol version matches packet with cmd_id {cmd_id}"
+            ))
+        }
+        [(protocol, _)] => *protocol,
+        [(first, _), ..] => candidates
+            .iter()
+            .find(|(_, request)| get_player_token::decrypt_client_rand_key(state, request).is_some())
+            .map(|(protocol, _)| *protocol)
+            .unwrap_or_else(|| {
+                warn!(
+                    "connection {}: initial packet decodes as GetPlayerTokenReq in versions {:?}, none of them carries a valid client_rand_key, falling back to {}",
+                    session.connection,
+                    candidates.iter().map(|(protocol, _)| protocol.version).collect::<Vec<_>>(),
+                    first.version
+                );
+                *first
+            }),
+    };
+
+    debug!(
+        "connection {} uses client protocol version {}",
+        session.connection, protocol.version
+    );
+
+    Ok(*session.protocol.get_or_init(|| protocol))
+}
+
+async fn kick_duplicate_sessions(state: &'static AppState, session: &Session, uid: u32) {
+    let duplicates = state
+        .sessions
+        .iter()
+        .filter(|s| {
+            s.connection.conv != session.connection.conv && s.player_uid.get() == Some(&uid)
+        })
+        .map(|s| s.value().clone())
+        .collect::<Vec<_>>();
+
+    for old_session in duplicates {
+        debug!(
+            "kicking session {} of player {uid}, relogin from {}",
+            old_session.connection, session.connection
+        );
+
+        old_session
+            .connection
+            .disconnect(ENetReason::EnetServerRelogin)
+            .await;
+    }
+}
+
+async fn notify_player_logout(state: &'static AppState, session: &Session) {
+    let Some(&uid) = session.player_uid.get() else {
+        return;
+    };
+
+    state
+        .game_server_socket
+        .send(make_raw_packet(
+            PlayerLogoutReq::CMD_ID,
+            PacketHead {
+                user_session_id: session.connection.conv,
+                user_id: uid,
+                ..Default::default()
+            },
+            &PlayerLogoutReq {
+                reason: PlayerLogoutReqReason::ReasonDisconnect.into(),
+            }
+            .encode_to_vec(),
+        ))
+        .await;
+}
+
 async fn handle_packet(
     state: &'static AppState,
     session: &Arc<Session>,
@@ -97,26 +199,88 @@ async fn handle_packet(
 
     let packet = RawPacket::new(&data)?;
     let head = packet.head();
-    let (cmd_id, body) = packet::client_to_normal(packet.cmd_id(), packet.body())?;
+    let protocol = match session.protocol.get() {
+        Some(protocol) => *protocol,
+        None => negotiate_protocol(state, session, packet.cmd_id(), packet.body())?,
+    };
+    let (cmd_id, body) = (protocol.client_to_normal)(packet.cmd_id(), packet.body())?;
 
     match cmd_id {
         GetPlayerTokenReq::CMD_ID => {
             let request = GetPlayerTokenReq::decode(body.as_ref())?;
             let session = session.clone();
+            let client_sequence_id = head.client_sequence_id;
 
             // Run in separate task because db fetch operation may take a while
             tokio::spawn(async move {
-                let Ok(uid) =
-                    util::fetch_user_uid(&state.db_connection, &request.account_uid).await
-                else {
-                    return;
-                };
+                let rsp = match util::verify_combo_token(
+                    &state.db_connection,
+                    &request.account_uid,
+                    &request.account_token,
+                )
+                .await
+                {
+                    Ok(true) => {
+                        match util::fetch_user_uid(&state.db_connection, &request.account_uid).await
+                        {
+                            Ok(uid) => {
+                                let rsp = get_player_token::process_message(
+                                    state, &session, request, uid,
+                                );
+
+                                if rsp.retcode == Retcode::RetSucc as i32 {
+                                    kick_duplicate_sessions(state, &session, uid).await;
+                                }
+
+                                rsp
+                            }
+                            Err(err) => {
+                                error!(
+                                    "GetPlayerTokenReq: failed to fetch uid, account_uid: {}, connection: {}, error: {err}",
+                                    request.account_uid, session.connection
+                                );
+
+                                GetPlayerTokenRsp {
+                                    retcode: Retcode::RetFail.into(),
+                                    ..Default::default()
+                                }
+                            }
+                        }
+                    }
+                    Ok(false) => {
+                        warn!(
+                            "GetPlayerTokenReq: combo token mismatch, account_uid: {}, connection: {}",
+                            request.account_uid, session.connection
+                        );
+
+                        GetPlayerTokenRsp {
+                            retcode: Retcode::RetTokenError.into(),
+                            ..Default::default()
+                        }
+                    }
+                    Err(err) => {
+                        error!(
+                            "GetPlayerTokenReq: failed to verify combo token, account_uid: {}, connection: {}, error: {err}",
+                            request.account_uid, session.connection
+                        );
 
-                let rsp = get_player_token::process_message(state, &session, request, uid);
+                        GetPlayerTokenRsp {
+                            retcode: Retcode::RetFail.into(),
+                            ..Default::default()
+                        }
+                    }
+                };
                 let (cmd_id, body) =
-                    packet::normal_to_client(rsp.get_cmd_id(), &rsp.encode_to_vec()).unwrap();
+                    (protocol.normal_to_client)(rsp.get_cmd_id(), &rsp.encode_to_vec()).unwrap();
 
-                let mut data = make_raw_packet(cmd_id, PacketHead::default(), &body);
+                let mut data = make_raw_packet(
+                    cmd_id,
+                    PacketHead {
+                        client_sequence_id,
+                        ..Default::default()
+                    },
+                    &body,
+                );
                 util::xor_packet(None, state.initial_xorpad.as_ref(), &mut data);
 
                 session.connection.send(data).await;
@@ -125,7 +289,7 @@ async fn handle_packet(
         PingReq::CMD_ID => {
             let request = PingReq::decode(body.as_ref())?;
 
-            let (cmd_id, body) = packet::normal_to_client(
+            let (cmd_id, body) = (protocol.normal_to_client)(
                 PingRsp::CMD_ID,
                 &PingRsp {
                     retcode: Retcode::RetSucc.into(),
@@ -136,7 +300,14 @@ async fn handle_packet(
             )
             .unwrap();
 
-            let mut data = make_raw_packet(cmd_id, PacketHead::default(), &body);
+            let mut data = make_raw_packet(
+                cmd_id,
+                PacketHead {
+                    client_sequence_id: head.client_sequence_id,
+                    ..Default::default()
+                },
+                &body,
+            );
             util::xor_packet(
                 session.xorpad.get(),
                 state.initial_xorpad.as_ref(),
@@ -147,7 +318,8 @@ async fn handle_packet(
         }
         UnionCmdNotify::CMD_ID => {
             let union_cmd_notify = UnionCmdNotify::decode(body.as_ref())?;
-            let mut new_packet = NetPacket::new(convert_union_cmd_notify_data(union_cmd_notify));
+            let mut new_packet =
+                NetPacket::new(convert_union_cmd_notify_data(protocol, union_cmd_notify));
 
             new_packet.head.user_session_id = session.connection.conv;
             new_packet.head.user_id = session.player_uid.get().copied().unwrap_or(0);
@@ -155,6 +327,8 @@ async fn handle_packet(
             state.game_server_socket.send(new_packet.encode()).await;
         }
         _ => {
+            let body = convert_invocations_client_to_normal(protocol, cmd_id, body);
+
             state
                 .game_server_socket
                 .send(make_raw_packet(
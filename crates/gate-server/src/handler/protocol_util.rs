@@ -1,32 +1,26 @@
 use mavuika_proto::{
-    packet::{self, combat_invocation_client_to_normal},
-    CmdID, CombatInvocationsNotify, CombatInvokeEntry, Protobuf, UnionCmd, UnionCmdNotify,
+    packet::{ClientProtocol, InvocationConverter, ProtocolConversionError},
+    AbilityInvocationsNotify, AbilityInvokeArgument, AbilityInvokeEntry, CmdID,
+    CombatInvocationsNotify, CombatInvokeEntry, CombatTypeArgument, Protobuf, UnionCmd,
+    UnionCmdNotify,
 };
 use tracing::{debug, instrument, warn};
 
 #[instrument(skip_all)]
-pub fn convert_union_cmd_notify_data(union_cmd_notify: UnionCmdNotify) -> UnionCmdNotify {
+pub fn convert_union_cmd_notify_data(
+    protocol: &ClientProtocol,
+    union_cmd_notify: UnionCmdNotify,
+) -> UnionCmdNotify {
     let mut cmd_list = Vec::with_capacity(union_cmd_notify.cmd_list.len());
     for union_cmd in union_cmd_notify.cmd_list {
         if let Ok((sub_cmd_id, sub_cmd_body)) =
-            packet::client_to_normal(union_cmd.message_id as u16, &union_cmd.body)
+            (protocol.client_to_normal)(union_cmd.message_id as u16, &union_cmd.body)
         {
-            if sub_cmd_id == CombatInvocationsNotify::CMD_ID {
-                if let Ok(combat_invocations_notify) =
-                    CombatInvocationsNotify::decode(sub_cmd_body.as_ref())
-                {
-                    cmd_list.push(UnionCmd {
-                        message_id: sub_cmd_id as u32,
-                        body: convert_combat_invocations_notify_data(combat_invocations_notify)
-                            .encode_to_vec(),
-                    });
-                }
-            } else {
-                cmd_list.push(UnionCmd {
-                    message_id: sub_cmd_id as u32,
-                    body: sub_cmd_body.into(),
-                });
-            }
+            cmd_list.push(UnionCmd {
+                message_id: sub_cmd_id as u32,
+                body: convert_invocations_client_to_normal(protocol, sub_cmd_id, sub_cmd_body)
+                    .into(),
+            });
         } else {
             warn!(
                 "UnionCmdNotify: couldn't convert sub cmd with id {}",
@@ -38,14 +32,70 @@ pub fn convert_union_cmd_notify_data(union_cmd_notify: UnionCmdNotify) -> UnionC
     UnionCmdNotify { cmd_list }
 }
 
-pub fn convert_combat_invocations_notify_data(
+// Converts invocation data nested in already converted (normal) packet body
+pub fn convert_invocations_client_to_normal(
+    protocol: &ClientProtocol,
+    cmd_id: u16,
+    body: Box<[u8]>,
+) -> Box<[u8]> {
+    convert_invocations(
+        cmd_id,
+        body,
+        protocol.combat_invocation_client_to_normal,
+        protocol.ability_invocation_client_to_normal,
+    )
+}
+
+// Converts invocation data nested in normal packet body, before the packet itself is converted
+pub fn convert_invocations_normal_to_client(
+    protocol: &ClientProtocol,
+    cmd_id: u16,
+    body: Box<[u8]>,
+) -> Box<[u8]> {
+    convert_invocations(
+        cmd_id,
+        body,
+        protocol.combat_invocation_normal_to_client,
+        protocol.ability_invocation_normal_to_client,
+    )
+}
+
+fn convert_invocations(
+    cmd_id: u16,
+    body: Box<[u8]>,
+    combat_converter: InvocationConverter<CombatTypeArgument>,
+    ability_converter: InvocationConverter<AbilityInvokeArgument>,
+) -> Box<[u8]> {
+    match cmd_id {
+        CombatInvocationsNotify::CMD_ID => match CombatInvocationsNotify::decode(body.as_ref()) {
+            Ok(notify) => convert_combat_invocations_notify_data(notify, combat_converter)
+                .encode_to_vec()
+                .into(),
+            Err(err) => {
+                warn!("failed to decode CombatInvocationsNotify: {err}");
+                body
+            }
+        },
+        AbilityInvocationsNotify::CMD_ID => match AbilityInvocationsNotify::decode(body.as_ref()) {
+            Ok(notify) => convert_ability_invocations_notify_data(notify, ability_converter)
+                .encode_to_vec()
+                .into(),
+            Err(err) => {
+                warn!("failed to decode AbilityInvocationsNotify: {err}");
+                body
+            }
+        },
+        _ => body,
+    }
+}
+
+fn convert_combat_invocations_notify_data(
     notify: CombatInvocationsNotify,
+    converter: InvocationConverter<CombatTypeArgument>,
 ) -> CombatInvocationsNotify {
     let mut entries = Vec::with_capacity(notify.invoke_list.len());
     for entry in notify.invoke_list {
-        if let Ok(data) =
-            combat_invocation_client_to_normal(entry.argument_type(), &entry.combat_data)
-        {
+        if let Ok(data) = converter(entry.argument_type(), &entry.combat_data) {
             debug!(
                 "converted CombatInvokeEntry with ArgumentType {:?}",
                 entry.argument_type()
@@ -68,3 +118,26 @@ pub fn convert_combat_invocations_notify_data(
         invoke_list: entries,
     }
 }
+
+fn convert_ability_invocations_notify_data(
+    notify: AbilityInvocationsNotify,
+    converter: InvocationConverter<AbilityInvokeArgument>,
+) -> AbilityInvocationsNotify {
+    let mut entries = Vec::with_capacity(notify.invokes.len());
+    for entry in notify.invokes {
+        match converter(entry.argument_type(), &entry.ability_data) {
+            Ok(data) => entries.push(AbilityInvokeEntry {
+                ability_data: data.into(),
+                ..entry
+            }),
+            // Many ability arguments carry no data, keep them as is
+            Err(ProtocolConversionError::NotFoundAbilityArgument(_)) => entries.push(entry),
+            Err(err) => warn!(
+                "failed to convert AbilityInvokeEntry with ArgumentType: {:?}, error: {err}",
+                entry.argument_type()
+            ),
+        }
+    }
+
+    AbilityInvocationsNotify { invokes: entries }
+}
@@ -1,11 +1,10 @@
-use mavuika_proto::{
-    packet::normal_to_client,
-    raw_packet::{make_raw_packet, RawPacket},
-};
+use mavuika_proto::raw_packet::{make_raw_packet, RawPacket};
 use tracing::{debug, warn};
 
 use crate::{util, AppState};
 
+use super::protocol_util::convert_invocations_normal_to_client;
+
 pub async fn on_message(state: &'static AppState, data: Box<[u8]>) {
     debug!("on_message: {}", hex::encode(&data));
 
@@ -17,7 +16,15 @@ pub async fn on_message(state: &'static AppState, data: Box<[u8]>) {
     let session_id = head.user_session_id;
 
     if let Some(session) = state.sessions.get(&session_id) {
-        match normal_to_client(packet.cmd_id(), packet.body()) {
+        let Some(protocol) = session.protocol.get() else {
+            warn!("on_message: session with id: {session_id} has no negotiated protocol");
+            return;
+        };
+
+        let body =
+            convert_invocations_normal_to_client(protocol, packet.cmd_id(), packet.body().into());
+
+        match (protocol.normal_to_client)(packet.cmd_id(), &body) {
             Ok((cmd_id, data)) => {
                 let mut data = make_raw_packet(cmd_id, head, &data);
                 util::xor_packet(
@@ -2,6 +2,7 @@ use std::{
     collections::HashMap,
     fs,
     sync::{Arc, LazyLock, OnceLock},
+    time::Duration,
 };
 
 use anyhow::Result;
@@ -16,7 +17,7 @@ use mavuika_database::DbConnection;
 use mavuika_encryption::{rsa::RsaKeyPair, xor::MhyXorpad};
 use mavuika_network::{listener, ServerSocket};
 use net::UdpServer;
-use tracing::Level;
+use tracing::{warn, Level};
 
 mod config;
 mod handler;
@@ -54,6 +55,12 @@ async fn main() -> Result<()> {
         .find(|r| r.name == CONFIG.cur_region_name)
         .expect("cur_region not found in region list");
 
+    cur_region
+        .bind_version_list
+        .iter()
+        .filter(|version| mavuika_proto::packet::client_protocol(version).is_none())
+        .for_each(|version| warn!("no client protocol registered for bound version {version}"));
+
     let initial_xorpad = if let Some(secret_key_path) = cur_region.secret_key_path.as_ref() {
         Some(MhyXorpad::from_ec2b(&fs::read(secret_key_path)?)?)
     } else {
@@ -81,7 +88,12 @@ async fn main() -> Result<()> {
     )
     .await?;
 
-    let udp_server = UdpServer::bind(CONFIG.network.udp_host.parse()?, state).await?;
+    let udp_server = UdpServer::bind(
+        CONFIG.network.udp_host.parse()?,
+        Duration::from_secs(CONFIG.network.session_idle_timeout_secs),
+        state,
+    )
+    .await?;
     udp_server.serve().await;
 
     Ok(())
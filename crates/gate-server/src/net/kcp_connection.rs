@@ -1,10 +1,20 @@
 use common::time_util;
 use kcp::Kcp;
-use std::{net::SocketAddr, sync::Arc, task};
-use tokio::{io::AsyncWrite, net::UdpSocket, sync::mpsc};
+use mavuika_proto::ENetReason;
+use std::{net::SocketAddr, sync::Arc, task, time::Duration};
+use tokio::{
+    io::AsyncWrite,
+    net::UdpSocket,
+    select,
+    sync::mpsc,
+    time::{self, Instant},
+};
+use tracing::debug;
 
 use crate::handler::PacketHandler;
 
+use super::control_packet::{ControlPacket, ControlPacketType};
+
 struct UdpOutput {
     peer_addr: SocketAddr,
     socket: Arc<UdpSocket>,
@@ -13,6 +23,7 @@ struct UdpOutput {
 pub enum NetEvent {
     Recv(Box<[u8]>),
     Send(Box<[u8]>),
+    Disconnect(ENetReason),
 }
 
 pub fn start(
@@ -21,6 +32,8 @@ pub fn start(
     socket: Arc<UdpSocket>,
     peer_addr: SocketAddr,
     handler: PacketHandler,
+    idle_timeout: Duration,
+    drop_tx: mpsc::UnboundedSender<(u32, u32)>,
 ) -> mpsc::Sender<NetEvent> {
     let (tx, rx) = mpsc::channel(32);
     let kcp = Kcp::new(
@@ -28,40 +41,86 @@ pub fn start(
         token,
         time_util::unix_timestamp_ms(),
         false,
-        UdpOutput { peer_addr, socket },
+        UdpOutput {
+            peer_addr,
+            socket: socket.clone(),
+        },
     );
 
-    tokio::spawn(async move { kcp_loop(kcp, rx, handler).await });
+    tokio::spawn(async move {
+        let Some(reason) = kcp_loop(kcp, rx, handler, idle_timeout).await else {
+            return;
+        };
+
+        // Connection is closed from our side, notify the peer and let server drop it.
+        let _ = socket
+            .send_to(
+                ControlPacket::build(ControlPacketType::Disconnect, conv, token, reason as u32)
+                    .as_slice(),
+                peer_addr,
+            )
+            .await;
+
+        let _ = drop_tx.send((conv, token));
+    });
+
     tx
 }
 
+// Returns the disconnect reason if connection was closed from server side
 async fn kcp_loop(
     mut kcp: Kcp<UdpOutput>,
     mut rx: mpsc::Receiver<NetEvent>,
     handler: PacketHandler,
-) {
+    idle_timeout: Duration,
+) -> Option<ENetReason> {
     let mut recv_buf = [0u8; 16384];
-    while let Some(event) = rx.recv().await {
-        match event {
-            NetEvent::Recv(buf) => {
-                kcp.input(&buf).unwrap();
-                kcp.async_update((time_util::unix_timestamp_ms() - kcp.start_ts()) as u32)
-                    .await
-                    .unwrap();
-                kcp.async_flush().await.unwrap();
-
-                while let Ok(len) = kcp.recv(&mut recv_buf) {
-                    handler.enqueue(kcp.conv(), recv_buf[..len].into());
+    let mut last_recv_time = Instant::now();
+
+    loop {
+        let next_update_time =
+            Instant::now() + Duration::from_millis(kcp.check(kcp_time(&kcp)) as u64);
+
+        select! {
+            event = rx.recv() => match event {
+                Some(NetEvent::Recv(buf)) => {
+                    last_recv_time = Instant::now();
+
+                    kcp.input(&buf).unwrap();
+                    kcp.async_update(kcp_time(&kcp)).await.unwrap();
+                    kcp.async_flush().await.unwrap();
+
+                    while let Ok(len) = kcp.recv(&mut recv_buf) {
+                        handler.enqueue(kcp.conv(), recv_buf[..len].into());
+                    }
+                }
+                Some(NetEvent::Send(buf)) => {
+                    kcp.send(&buf).unwrap();
+                    kcp.async_flush().await.unwrap();
+                }
+                Some(NetEvent::Disconnect(reason)) => return Some(reason),
+                // Connection was removed by the server
+                None => return None,
+            },
+            () = time::sleep_until(next_update_time) => {
+                if last_recv_time.elapsed() >= idle_timeout {
+                    debug!("connection [{}|{}] idle timeout", kcp.conv(), kcp.token());
+                    return Some(ENetReason::EnetTimeout);
+                }
+
+                if kcp.async_update(kcp_time(&kcp)).await.is_err() || kcp.is_dead_link() {
+                    debug!("connection [{}|{}] is dead", kcp.conv(), kcp.token());
+                    return Some(ENetReason::EnetTimeout);
                 }
-            }
-            NetEvent::Send(buf) => {
-                kcp.send(&buf).unwrap();
-                kcp.async_flush().await.unwrap();
             }
         }
     }
 }
 
+fn kcp_time(kcp: &Kcp<UdpOutput>) -> u32 {
+    (time_util::unix_timestamp_ms() - kcp.start_ts()) as u32
+}
+
 impl AsyncWrite for UdpOutput {
     fn poll_write(
         self: std::pin::Pin<&mut Self>,
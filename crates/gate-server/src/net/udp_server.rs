@@ -1,8 +1,9 @@
-use std::{collections::HashMap, fmt, net::SocketAddr, sync::Arc};
+use std::{collections::HashMap, fmt, net::SocketAddr, sync::Arc, time::Duration};
 
 use kcp::KCP_OVERHEAD;
+use mavuika_proto::ENetReason;
 use rand::RngCore;
-use tokio::{net::UdpSocket, sync::mpsc};
+use tokio::{net::UdpSocket, select, sync::mpsc};
 use tracing::debug;
 
 use crate::{handler::PacketHandler, AppState};
@@ -15,26 +16,47 @@ use super::{
 pub struct UdpServer {
     socket: Arc<UdpSocket>,
     packet_handler: PacketHandler,
+    idle_timeout: Duration,
 }
 
 impl UdpServer {
-    pub async fn bind(addr: SocketAddr, state: &'static AppState) -> std::io::Result<Self> {
+    pub async fn bind(
+        addr: SocketAddr,
+        idle_timeout: Duration,
+        state: &'static AppState,
+    ) -> std::io::Result<Self> {
         let socket = UdpSocket::bind(addr).await?;
         let packet_handler = PacketHandler::new(state);
 
         Ok(Self {
             socket: Arc::new(socket),
             packet_handler,
+            idle_timeout,
         })
     }
 
     pub async fn serve(self) {
-        let mut conn_mgr = ConnectionManager::default();
+        let (drop_tx, mut drop_rx) = mpsc::unbounded_channel();
+        let mut conn_mgr = ConnectionManager::new(self.idle_timeout, drop_tx);
         let mut buf = [0u8; 1400];
 
         loop {
-            let Ok((len, addr)) = self.socket.recv_from(&mut buf).await else {
-                continue;
+            let (len, addr) = select! {
+                result = self.socket.recv_from(&mut buf) => {
+                    let Ok((len, addr)) = result else {
+                        continue;
+                    };
+
+                    (len, addr)
+                }
+                Some((conv, token)) = drop_rx.recv() => {
+                    if let Some(id) = conn_mgr.remove(conv, token) {
+                        self.packet_handler.remove_connection(id.conv);
+                        debug!("connection {id} closed by server");
+                    }
+
+                    continue;
+                }
             };
 
             match len {
@@ -119,6 +141,10 @@ impl Connection {
     pub async fn send(&self, data: Box<[u8]>) {
         let _ = self.event_tx.send(NetEvent::Send(data)).await;
     }
+
+    pub async fn disconnect(&self, reason: ENetReason) {
+        let _ = self.event_tx.send(NetEvent::Disconnect(reason)).await;
+    }
 }
 
 impl fmt::Display for Connection {
@@ -127,13 +153,23 @@ impl fmt::Display for Connection {
     }
 }
 
-#[derive(Default)]
 struct ConnectionManager {
     connections: HashMap<u32, Connection>,
     connection_counter: u32,
+    idle_timeout: Duration,
+    drop_tx: mpsc::UnboundedSender<(u32, u32)>,
 }
 
 impl ConnectionManager {
+    fn new(idle_timeout: Duration, drop_tx: mpsc::UnboundedSender<(u32, u32)>) -> Self {
+        Self {
+            connections: HashMap::new(),
+            connection_counter: 0,
+            idle_timeout,
+            drop_tx,
+        }
+    }
+
     fn create(
         &mut self,
         socket: Arc<UdpSocket>,
@@ -142,7 +178,15 @@ impl ConnectionManager {
     ) -> &Connection {
         self.connection_counter += 1;
         let (conv, token) = (self.connection_counter, rand::thread_rng().next_u32());
-        let event_tx = kcp_connection::start(conv, token, socket, addr, handler);
+        let event_tx = kcp_connection::start(
+            conv,
+            token,
+            socket,
+            addr,
+            handler,
+            self.idle_timeout,
+            self.drop_tx.clone(),
+        );
 
         let id = Connection {
             conv,
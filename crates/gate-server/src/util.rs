@@ -1,5 +1,3 @@
-use tracing::error;
-
 use mavuika_database::{sql_op, DbConnection, DbError};
 use mavuika_encryption::xor::MhyXorpad;
 
@@ -15,15 +13,19 @@ pub fn xor_packet(
     }
 }
 
-pub async fn fetch_user_uid(db_conn: &DbConnection, account_uid: &str) -> Result<u32, DbError> {
-    match sql_op::select_user_uid_by_account_uid(db_conn, account_uid)
+pub async fn verify_combo_token(
+    db_conn: &DbConnection,
+    account_uid: &str,
+    account_token: &str,
+) -> Result<bool, DbError> {
+    sql_op::select_combo_token_by_account_and_token(db_conn, account_uid, account_token)
         .await
-        .inspect_err(|err| error!("failed to select user uid: {err}"))?
-    {
+        .map(|token| token.is_some())
+}
+
+pub async fn fetch_user_uid(db_conn: &DbConnection, account_uid: &str) -> Result<u32, DbError> {
+    match sql_op::select_user_uid_by_account_uid(db_conn, account_uid).await? {
         Some(uid) => Ok(uid.uid as u32),
-        None => Ok(sql_op::insert_user_uid(db_conn, account_uid)
-            .await
-            .inspect_err(|err| error!("failed to insert user uid: {err}"))?
-            .uid as u32),
+        None => Ok(sql_op::insert_user_uid(db_conn, account_uid).await?.uid as u32),
     }
 }
@@ -28,7 +28,7 @@ pub fn handle_appearance_change_request(
                 message_output.send_to_all(notify);
             }
 
-            message_output.send(message.sender_uid(), rsp);
+            message_output.reply(message, rsp);
         } else if let Some(request) = message.decode::<AvatarChangeCostumeReq>() {
             let player = players.get_mut(message.sender_uid());
             let mut rsp = AvatarChangeCostumeRsp::default();
@@ -37,7 +37,7 @@ pub fn handle_appearance_change_request(
                 change_events.send(change_event);
             }
 
-            message_output.send(message.sender_uid(), rsp);
+            message_output.reply(message, rsp);
         } else if let Some(request) = message.decode::<AvatarChangeTraceEffectReq>() {
             let player = players.get_mut(message.sender_uid());
             let mut rsp = AvatarChangeTraceEffectRsp::default();
@@ -46,7 +46,7 @@ pub fn handle_appearance_change_request(
                 change_events.send(change_event);
             }
 
-            message_output.send(message.sender_uid(), rsp);
+            message_output.reply(message, rsp);
         }
     }
 }
@@ -42,12 +42,16 @@ pub fn entity_movement(
     }
 }
 
+type MovedPlayerAvatarQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Transform, &'static OwnerPlayerUID),
+    (With<CurrentPlayerAvatarMarker>, Changed<Transform>),
+>;
+
 #[instrument(skip_all)]
 pub fn track_player_position(
-    moved_player_avatars: Query<
-        (&Transform, &OwnerPlayerUID),
-        (With<CurrentPlayerAvatarMarker>, Changed<Transform>),
-    >,
+    moved_player_avatars: MovedPlayerAvatarQuery,
     mut players: ResMut<Players>,
 ) {
     for (transform, owner_uid) in moved_player_avatars.iter() {
@@ -6,7 +6,7 @@ pub use sdk_sql_op::{
 use sqlx::{query, query_as};
 
 use crate::{
-    data::{PlayerDataRow, UserUidRow},
+    data::{ComboToken, PlayerDataRow, UserUidRow},
     DbConnection, DbError,
 };
 
@@ -46,6 +46,19 @@ pub async fn select_user_uid_by_account_uid(
         .map_err(DbError::from)
 }
 
+pub async fn select_combo_token_by_account_and_token(
+    conn: &DbConnection,
+    account_uid: &str,
+    token: &str,
+) -> Result<Option<ComboToken>, DbError> {
+    query_as("SELECT * FROM t_combo_token WHERE account_uid = ($1) AND token = ($2)")
+        .bind(account_uid)
+        .bind(token)
+        .fetch_optional(&conn.0)
+        .await
+        .map_err(DbError::from)
+}
+
 pub async fn insert_user_uid(
     conn: &DbConnection,
     account_uid: &str,
@@ -14,15 +14,19 @@ use tracing::{debug, instrument};
 #[derive(Resource, Default)]
 pub struct ChairLockMap(HashMap<u64, (u32, u32)>);
 
+type ActiveAvatarQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static OwnerPlayerUID, &'static ProtocolEntityID),
+    (With<CurrentPlayerAvatarMarker>, Without<ToBeRemovedMarker>),
+>;
+
 #[instrument(skip_all)]
 pub fn avatar_lock_chair(
     mut events: EventReader<ClientMessageEvent>,
     out: Res<MessageOutput>,
     mut lock: ResMut<ChairLockMap>,
-    active_entities: Query<
-        (&OwnerPlayerUID, &ProtocolEntityID),
-        (With<CurrentPlayerAvatarMarker>, Without<ToBeRemovedMarker>),
-    >,
+    active_entities: ActiveAvatarQuery,
 ) {
     for message in events.read() {
         if let Some(request) = message.decode::<EvtAvatarLockChairReq>() {
@@ -52,7 +56,7 @@ pub fn avatar_lock_chair(
                 rsp.retcode = Retcode::RetFail.into();
             }
 
-            out.send(message.sender_uid(), rsp);
+            out.reply(message, rsp);
         } else if message.decode::<EvtAvatarStandUpNotify>().is_some() {
             let uid = message.sender_uid();
 
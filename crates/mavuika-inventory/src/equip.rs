@@ -42,8 +42,8 @@ pub fn change_avatar_equip(
                 weapon_guid: request.equip_guid,
             });
 
-            message_output.send(
-                message.sender_uid(),
+            message_output.reply(
+                message,
                 WearEquipRsp {
                     retcode: Retcode::RetSucc.into(),
                     avatar_guid: request.avatar_guid,
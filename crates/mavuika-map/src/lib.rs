@@ -21,8 +21,8 @@ fn data_request_processor(
 
     for message in events.read() {
         if let Some(request) = message.decode::<GetSceneAreaReq>() {
-            message_output.send(
-                message.sender_uid(),
+            message_output.reply(
+                message,
                 GetSceneAreaRsp {
                     retcode: Retcode::RetSucc.into(),
                     city_info_list: Vec::with_capacity(0),
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use bevy_ecs::system::Resource;
 use mavuika_proto::{PacketHead, YSMessage};
+
+use crate::event::ClientMessageEvent;
 use tokio::sync::mpsc;
 
 #[derive(Clone)]
@@ -15,12 +17,36 @@ impl MessageOutput {
         Self(client_map)
     }
 
+    pub fn replace(&mut self, player_uid: u32, output: ClientOutput) -> Option<ClientOutput> {
+        self.0.insert(player_uid, output)
+    }
+
     pub fn send(&self, player_uid: u32, message: impl YSMessage) {
         if let Some(out) = self.0.get(&player_uid) {
             out.push(PacketHead::default(), message);
         }
     }
 
+    pub fn reply(&self, request: &ClientMessageEvent, message: impl YSMessage) {
+        self.send_response(
+            request.sender_uid(),
+            request.head().client_sequence_id,
+            message,
+        );
+    }
+
+    pub fn send_response(&self, player_uid: u32, client_sequence_id: u32, message: impl YSMessage) {
+        if let Some(out) = self.0.get(&player_uid) {
+            out.push(
+                PacketHead {
+                    client_sequence_id,
+                    ..Default::default()
+                },
+                message,
+            );
+        }
+    }
+
     pub fn send_to_all(&self, message: impl YSMessage + Clone) {
         for out in self.0.values() {
             out.push(PacketHead::default(), message.clone());
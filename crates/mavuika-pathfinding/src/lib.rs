@@ -22,7 +22,7 @@ fn pathfinding_packet_processor(
     for message in events.read() {
         if let Some(request) = message.decode::<PathfindingEnterSceneReq>() {
             debug!("PathfindingEnterScene: {request:?}");
-            message_output.send(message.sender_uid(), PathfindingEnterSceneRsp::default())
+            message_output.reply(message, PathfindingEnterSceneRsp::default())
         } else if let Some(request) = message.decode::<QueryPathReq>() {
             debug!("QueryPath: {request:?}");
 
@@ -36,8 +36,8 @@ fn pathfinding_packet_processor(
                 corners.push(*destination);
             }
 
-            message_output.send(
-                message.sender_uid(),
+            message_output.reply(
+                message,
                 QueryPathRsp {
                     retcode: Retcode::RetSucc.into(),
                     query_status: QueryPathRspPathStatusType::PathStatusTypeSucc.into(),
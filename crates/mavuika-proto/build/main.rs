@@ -1,28 +1,59 @@
 use std::{
-    collections::HashMap,
+    collections::BTreeMap,
     fs::{self, read_to_string, File},
     io::{BufRead, BufReader},
     path::Path,
 };
 
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
-use syn::{Field, Ident, Item, Type, TypePath};
+use serde::Deserialize;
+use syn::{ext::IdentExt, Field, Ident, Item, Type, TypePath};
+
+// Optional per-version aliases of message fields named differently in client proto, e.g:
+//
+// ["CNCBWin5.2.51".PingReq.fields]
+// seq = "kfneabncala" # normal field = client field
+const PROTO_MAPPING_FILE: &str = "proto_mapping.toml";
+
+type ProtoMapping = BTreeMap<String, VersionMapping>;
+type VersionMapping = BTreeMap<String, MessageMapping>;
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct MessageMapping {
+    #[serde(default)]
+    fields: BTreeMap<String, String>,
+}
 
 pub fn main() {
     println!("cargo:rerun-if-changed=proto");
+    println!("cargo:rerun-if-changed={PROTO_MAPPING_FILE}");
 
-    const CLIENT_PROTO_FILE: &str = "proto/client.proto";
+    // Every client version has its own proto set: proto/client/<version>.proto
+    const CLIENT_PROTO_DIR: &str = "proto/client";
+    const CLIENT_GEN_DIR: &str = "gen/client";
     const NORMAL_PROTO_FILE: &str = "proto/normalized.proto";
 
     let _ = fs::create_dir("gen/");
-    if Path::new(CLIENT_PROTO_FILE).exists() {
-        prost_build::Config::new()
-            .out_dir("gen/")
-            .type_attribute(".", "#[derive(mavuika_proto_derive::CmdID)]")
-            .compile_protos(&[CLIENT_PROTO_FILE], &["."])
-            .unwrap();
+    if let Ok(entries) = fs::read_dir(CLIENT_PROTO_DIR) {
+        for path in entries.flatten().map(|e| e.path()) {
+            if path.extension().is_none_or(|ext| ext != "proto") {
+                continue;
+            }
 
-        implement_cmd_id(Path::new("gen/client.rs")).unwrap();
+            let version = path.file_stem().unwrap().to_str().unwrap();
+            let out_dir = Path::new(CLIENT_GEN_DIR).join(version);
+            fs::create_dir_all(&out_dir).unwrap();
+
+            prost_build::Config::new()
+                .out_dir(&out_dir)
+                .type_attribute(".", "#[derive(mavuika_proto_derive::CmdID)]")
+                .compile_protos(&[&path], &["."])
+                .unwrap();
+
+            implement_cmd_id(&out_dir.join("client.rs")).unwrap();
+        }
     }
 
     if Path::new(NORMAL_PROTO_FILE).exists() {
@@ -35,16 +66,127 @@ pub fn main() {
         implement_cmd_id(Path::new("gen/normal.rs")).unwrap();
     }
 
-    impl_proto_conversion(
-        Path::new("gen/normal.rs"),
-        Path::new("gen/client.rs"),
+    let mut versions = fs::read_dir(CLIENT_GEN_DIR)
+        .unwrap()
+        .flatten()
+        .filter(|e| e.path().is_dir())
+        .map(|e| e.file_name().into_string().unwrap())
+        .collect::<Vec<_>>();
+    versions.sort();
+
+    let mapping = load_proto_mapping(Path::new(PROTO_MAPPING_FILE));
+
+    for version in versions.iter() {
+        let dir = Path::new(CLIENT_GEN_DIR).join(version);
+        let mod_ident = version_mod_ident(version);
+
+        impl_proto_conversion(
+            Path::new("gen/normal.rs"),
+            &dir.join("client.rs"),
+            &dir.join("client_to_normal.rs"),
+            &dir.join("normal_to_client.rs"),
+            &dir.join("conversion.rs"),
+            quote!(crate::client::#mod_ident),
+            version,
+            mapping.get(version),
+        )
+        .unwrap();
+    }
+
+    impl_version_registry(
+        &versions,
+        Path::new("gen/client_versions.rs"),
         Path::new("gen/client_to_normal.rs"),
-        Path::new("gen/normal_to_client.rs"),
         Path::new("gen/conversion.rs"),
     )
     .unwrap();
 }
 
+fn load_proto_mapping(path: &Path) -> ProtoMapping {
+    match read_to_string(path) {
+        Ok(data) => toml::from_str(&data)
+            .unwrap_or_else(|err| panic!("failed to parse {}: {err}", path.display())),
+        Err(_) => ProtoMapping::default(),
+    }
+}
+
+fn version_mod_ident(version: &str) -> Ident {
+    let name = version
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+
+    Ident::new(&format!("v_{name}"), Span::call_site())
+}
+
+// Generates per-version modules and the runtime registry of protocol converters
+fn impl_version_registry(
+    versions: &[String],
+    client_out: &Path,
+    c2n_out: &Path,
+    conversion_out: &Path,
+) -> std::io::Result<()> {
+    let mut client_modules = quote! {};
+    let mut client_to_normal_impls = quote! {};
+    let mut conversion_modules = quote! {};
+    let mut protocols = quote! {};
+
+    for version in versions {
+        let mod_ident = version_mod_ident(version);
+        let client_path = format!("client/{version}/client.rs");
+        let n2c_path = format!("client/{version}/normal_to_client.rs");
+        let c2n_path = format!("client/{version}/client_to_normal.rs");
+        let conversion_path = format!("client/{version}/conversion.rs");
+
+        client_modules.extend(quote! {
+            pub mod #mod_ident {
+                include!(#client_path);
+                include!(#n2c_path);
+            }
+        });
+
+        client_to_normal_impls.extend(quote! {
+            include!(#c2n_path);
+        });
+
+        conversion_modules.extend(quote! {
+            pub mod #mod_ident {
+                include!(#conversion_path);
+            }
+        });
+
+        protocols.extend(quote! {
+            ClientProtocol {
+                version: #version,
+                client_to_normal: #mod_ident::client_to_normal,
+                normal_to_client: #mod_ident::normal_to_client,
+                combat_invocation_client_to_normal: #mod_ident::combat_invocation_client_to_normal,
+                combat_invocation_normal_to_client: #mod_ident::combat_invocation_normal_to_client,
+                ability_invocation_client_to_normal: #mod_ident::ability_invocation_client_to_normal,
+                ability_invocation_normal_to_client: #mod_ident::ability_invocation_normal_to_client,
+            },
+        });
+    }
+
+    let registry = quote! {
+        #conversion_modules
+
+        pub static CLIENT_PROTOCOLS: &[ClientProtocol] = &[#protocols];
+    };
+
+    for (path, tokens) in [
+        (client_out, client_modules),
+        (c2n_out, client_to_normal_impls),
+        (conversion_out, registry),
+    ] {
+        let ast = syn::parse2(tokens).unwrap();
+        fs::write(path, prettyplease::unparse(&ast))?;
+    }
+
+    Ok(())
+}
+
 #[must_use]
 fn field_is_type(field: &Field, type_name: &str) -> bool {
     match &field.ty {
@@ -56,12 +198,21 @@ fn field_is_type(field: &Field, type_name: &str) -> bool {
     }
 }
 
+#[must_use]
+fn field_name(field: &Field) -> String {
+    field.ident.as_ref().unwrap().unraw().to_string()
+}
+
+#[allow(clippy::too_many_arguments)]
 fn impl_proto_conversion(
     normal_path: &Path,
     client_path: &Path,
     c2n_out: &Path,
     n2c_out: &Path,
     conversion_out: &Path,
+    client_mod: TokenStream,
+    version: &str,
+    mapping: Option<&VersionMapping>,
 ) -> std::io::Result<()> {
     let normal_file = read_to_string(normal_path)?;
     let client_file = read_to_string(client_path)?;
@@ -86,45 +237,66 @@ fn impl_proto_conversion(
                     continue;
                 };
 
-                let mut assignments = quote! {};
-                client
-                    .fields
-                    .iter()
-                    .filter(|f| normal.fields.iter().any(|ff| ff.ident == f.ident))
-                    .map(|f| (f, f.ident.as_ref().unwrap()))
-                    .for_each(|(f, ident)| {
-                        if field_is_type(f, "Option") {
-                            assignments = quote! {
-                                #assignments
-                                #ident: value.#ident.map(|v| v.into()),
-                            };
-                        }
-                        else if field_is_type(f, "Vec") {
-                            assignments = quote! {
-                                #assignments
-                                #ident: value.#ident.into_iter().map(|v| v.into()).collect(),
-                            };
-                        }
-                        else if field_is_type(f, "HashMap") {
-                            assignments = quote! {
-                                #assignments
-                                #ident: value.#ident.into_iter().map(|(k, v)| (k.into(), v.into())).collect(),
-                            };
-                        }
-                        else {
-                            assignments = quote! {
-                                #assignments
-                                #ident: value.#ident.into(),
-                            };
-                        }
-                    });
+                let aliases = mapping
+                    .and_then(|m| m.get(&ident.to_string()))
+                    .map(|m| &m.fields);
+                for client_name in aliases.into_iter().flat_map(|a| a.values()) {
+                    if !client.fields.iter().any(|f| field_name(f) == *client_name) {
+                        panic!("proto mapping ({version}): {ident} has no client field {client_name}");
+                    }
+                }
+
+                let mut c2n_assignments = quote! {};
+                let mut n2c_assignments = quote! {};
+                for client_field in client.fields.iter() {
+                    let client_name = field_name(client_field);
+                    let normal_name = aliases
+                        .and_then(|a| a.iter().find(|(_, alias)| **alias == client_name))
+                        .map_or(client_name.as_str(), |(normal_name, _)| {
+                            normal_name.as_str()
+                        });
+
+                    let Some(normal_field) =
+                        normal.fields.iter().find(|f| field_name(f) == normal_name)
+                    else {
+                        continue;
+                    };
+
+                    let client_ident = client_field.ident.as_ref().unwrap();
+                    let normal_ident = normal_field.ident.as_ref().unwrap();
+
+                    let (c2n_value, n2c_value) = if field_is_type(client_field, "Option") {
+                        (
+                            quote!(value.#client_ident.map(|v| v.into())),
+                            quote!(value.#normal_ident.map(|v| v.into())),
+                        )
+                    } else if field_is_type(client_field, "Vec") {
+                        (
+                            quote!(value.#client_ident.into_iter().map(|v| v.into()).collect()),
+                            quote!(value.#normal_ident.into_iter().map(|v| v.into()).collect()),
+                        )
+                    } else if field_is_type(client_field, "HashMap") {
+                        (
+                            quote!(value.#client_ident.into_iter().map(|(k, v)| (k.into(), v.into())).collect()),
+                            quote!(value.#normal_ident.into_iter().map(|(k, v)| (k.into(), v.into())).collect()),
+                        )
+                    } else {
+                        (
+                            quote!(value.#client_ident.into()),
+                            quote!(value.#normal_ident.into()),
+                        )
+                    };
+
+                    c2n_assignments.extend(quote!(#normal_ident: #c2n_value,));
+                    n2c_assignments.extend(quote!(#client_ident: #n2c_value,));
+                }
 
                 client_from_impls = quote! {
                     #client_from_impls
                     impl From<crate::normal::#ident> for #ident {
                         fn from(value: crate::normal::#ident) -> Self {
                             Self {
-                                #assignments
+                                #n2c_assignments
                                 ..Default::default()
                             }
                         }
@@ -133,10 +305,10 @@ fn impl_proto_conversion(
 
                 normal_from_impls = quote! {
                     #normal_from_impls
-                    impl From<crate::client::#ident> for #ident {
-                        fn from(value: crate::client::#ident) -> Self {
+                    impl From<#client_mod::#ident> for #ident {
+                        fn from(value: #client_mod::#ident) -> Self {
                             Self {
-                                #assignments
+                                #c2n_assignments
                                 ..Default::default()
                             }
                         }
@@ -201,7 +373,7 @@ fn impl_proto_conversion(
 
                                 c2n_match_arms = quote! {
                                     #c2n_match_arms
-                                    crate::client::#mod_ident::#oneof_ident::#ident(v) => Self::#ident(v.into()),
+                                    #client_mod::#mod_ident::#oneof_ident::#ident(v) => Self::#ident(v.into()),
                                 };
                             });
 
@@ -219,8 +391,8 @@ fn impl_proto_conversion(
 
                         normal_from_impls = quote! {
                             #normal_from_impls
-                            impl From<crate::client::#mod_ident::#oneof_ident> for #mod_ident::#oneof_ident {
-                                fn from(value: crate::client::#mod_ident::#oneof_ident) -> Self {
+                            impl From<#client_mod::#mod_ident::#oneof_ident> for #mod_ident::#oneof_ident {
+                                fn from(value: #client_mod::#mod_ident::#oneof_ident) -> Self {
                                     match value {
                                         #c2n_match_arms
                                         _ => unreachable!(),
@@ -260,8 +432,8 @@ fn impl_proto_conversion(
             }) {
                 client_to_normal_packet = quote! {
                     #client_to_normal_packet
-                    crate::client::#ident::CMD_ID => {
-                        let proto = crate::client::#ident::decode(body)?;
+                    #client_mod::#ident::CMD_ID => {
+                        let proto = #client_mod::#ident::decode(body)?;
                         let proto: crate::normal::#ident = proto.into();
 
                         Ok((crate::normal::#ident::CMD_ID, proto.encode_to_vec().into_boxed_slice()))
@@ -272,50 +444,168 @@ fn impl_proto_conversion(
                     #normal_to_client_packet
                     crate::normal::#ident::CMD_ID => {
                         let proto = crate::normal::#ident::decode(body)?;
-                        let proto: crate::client::#ident = proto.into();
+                        let proto: #client_mod::#ident = proto.into();
 
-                        Ok((crate::client::#ident::CMD_ID, proto.encode_to_vec().into_boxed_slice()))
+                        Ok((#client_mod::#ident::CMD_ID, proto.encode_to_vec().into_boxed_slice()))
                     },
                 };
             }
         }
     }
 
-    let mut client_to_normal_combat_invocation = quote! {};
-    let mut normal_to_client_combat_invocation = quote! {};
-
-    let combat_invoke_names: HashMap<&'static str, &'static str> = HashMap::from([
-        ("EntityMoveInfo", "EntityMove"),
+    // CombatTypeArgument -> combat_data message
+    let combat_invoke_names = [
         ("EvtBeingHitInfo", "EvtBeingHit"),
-    ]);
-
-    for item in client_file.items.iter() {
-        if let Item::Struct(client) = item {
-            let ident = &client.ident;
-            if let Some(combat_enum_case) = combat_invoke_names.get(ident.to_string().as_str()) {
-                let case_ident = Ident::new(combat_enum_case, ident.span());
-                client_to_normal_combat_invocation.extend(quote! {
-                    crate::normal::CombatTypeArgument::#case_ident => {
-                        let proto = crate::client::#ident::decode(combat_data)?;
-                        let proto: crate::normal::#ident = proto.into();
-
-                        Ok(proto.encode_to_vec().into_boxed_slice())
-                    }
-                });
-
-                normal_to_client_combat_invocation.extend(quote! {
-                    crate::normal::CombatTypeArgument::#case_ident => {
-                        let proto = crate::normal::#ident::decode(combat_data)?;
-                        let proto: crate::client::#ident = proto.into();
-
-                        Ok(proto.encode_to_vec().into_boxed_slice())
-                    }
-                });
-            }
-        }
-    }
+        ("EvtAnimatorStateChangedInfo", "AnimatorStateChanged"),
+        ("EvtFaceToDirInfo", "FaceToDir"),
+        ("EvtSetAttackTargetInfo", "SetAttackTarget"),
+        ("EvtRushMoveInfo", "RushMove"),
+        ("EvtAnimatorParameterInfo", "AnimatorParameterChanged"),
+        ("EntityMoveInfo", "EntityMove"),
+        ("EvtSyncEntityPositionInfo", "SyncEntityPosition"),
+        ("EvtCombatSteerMotionInfo", "SteerMotionInfo"),
+        ("EvtCombatForceSetPosInfo", "ForceSetPosInfo"),
+        ("EvtCompensatePosDiffInfo", "CompensatePosDiff"),
+        ("EvtMonsterDoBlink", "MonsterDoBlink"),
+        ("EvtFixedRushMove", "FixedRushMove"),
+        ("EvtSyncTransform", "SyncTransform"),
+        ("EvtLightCoreMove", "LightCoreMove"),
+        ("EvtBeingHealedNotify", "BeingHealedNtf"),
+        ("EvtSyncSkillAnchorPosition", "SkillAnchorPositionNtf"),
+        ("EvtGrapplingHookMove", "GrapplingHookMove"),
+    ];
+
+    // AbilityInvokeArgument -> ability_data message
+    let ability_invoke_names = [
+        ("AbilityMetaModifierChange", "AbilityMetaModifierChange"),
+        ("AbilityScalarValueEntry", "AbilityMetaOverrideParam"),
+        (
+            "AbilityMetaReInitOverrideMap",
+            "AbilityMetaReinitOverridemap",
+        ),
+        ("AbilityScalarValueEntry", "AbilityMetaGlobalFloatValue"),
+        ("AbilityMetaSetKilledState", "AbilityMetaSetKilledState"),
+        ("AbilityMetaAddAbility", "AbilityMetaAddNewAbility"),
+        (
+            "AbilityMetaSetModifierApplyEntityId",
+            "AbilityMetaSetModifierApplyEntity",
+        ),
+        (
+            "AbilityMetaModifierDurabilityChange",
+            "AbilityMetaModifierDurabilityChange",
+        ),
+        ("AbilityMetaSetPoseParameter", "AbilityMetaSetPoseParameter"),
+        (
+            "AbilityMetaUpdateBaseReactionDamage",
+            "AbilityMetaUpdateBaseReactionDamage",
+        ),
+        (
+            "AbilityMetaTriggerElementReaction",
+            "AbilityMetaTriggerElementReaction",
+        ),
+        ("AbilityMetaLoseHp", "AbilityMetaLoseHp"),
+        ("AbilityMetaDurabilityIsZero", "AbilityMetaDurabilityIsZero"),
+        ("AbilityActionTriggerAbility", "AbilityActionTriggerAbility"),
+        ("AbilityActionSetCrashDamage", "AbilityActionSetCrashDamage"),
+        ("AbilityActionSummon", "AbilityActionSummon"),
+        ("AbilityActionBlink", "AbilityActionBlink"),
+        ("AbilityActionCreateGadget", "AbilityActionCreateGadget"),
+        (
+            "AbilityApplyLevelModifier",
+            "AbilityActionApplyLevelModifier",
+        ),
+        (
+            "AbilityActionGenerateElemBall",
+            "AbilityActionGenerateElemBall",
+        ),
+        (
+            "AbilityActionSetRandomOverrideMapValue",
+            "AbilityActionSetRandomOverrideMapValue",
+        ),
+        (
+            "AbilityActionServerMonsterLog",
+            "AbilityActionServerMonsterLog",
+        ),
+        ("AbilityActionCreateTile", "AbilityActionCreateTile"),
+        ("AbilityActionDestroyTile", "AbilityActionDestroyTile"),
+        ("AbilityActionFireAfterImage", "AbilityActionFireAfterImage"),
+        ("AbilityActionDeductStamina", "AbilityActionDeductStamina"),
+        ("AbilityActionHitEffect", "AbilityActionHitEffect"),
+        (
+            "AbilityActionSetBulletTrackTarget",
+            "AbilityActionSetBulletTrackTarget",
+        ),
+        (
+            "AbilityMixinAvatarSteerByCamera",
+            "AbilityMixinAvatarSteerByCamera",
+        ),
+        ("AbilityMixinWindZone", "AbilityMixinWindZone"),
+        ("AbilityMixinCostStamina", "AbilityMixinCostStamina"),
+        ("AbilityMixinEliteShield", "AbilityMixinEliteShield"),
+        ("AbilityMixinElementShield", "AbilityMixinElementShield"),
+        ("AbilityMixinGlobalShield", "AbilityMixinGlobalShield"),
+        ("AbilityMixinShieldBar", "AbilityMixinShieldBar"),
+        ("AbilityMixinWindSeedSpawner", "AbilityMixinWindSeedSpawner"),
+        (
+            "AbilityMixinDoActionByElementReaction",
+            "AbilityMixinDoActionByElementReaction",
+        ),
+        (
+            "AbilityMixinFieldEntityCountChange",
+            "AbilityMixinFieldEntityCountChange",
+        ),
+        ("AbilityMixinScenePropSync", "AbilityMixinScenePropSync"),
+        ("AbilityMixinWidgetMpSupport", "AbilityMixinWidgetMpSupport"),
+        (
+            "AbilityMixinDoActionBySelfModifierElementDurabilityRatio",
+            "AbilityMixinDoActionBySelfModifierElementDurabilityRatio",
+        ),
+        (
+            "AbilityMixinFireworksLauncher",
+            "AbilityMixinFireworksLauncher",
+        ),
+        ("AbilityMixinUgcTimeControl", "AbilityMixinUgcTimeControl"),
+        ("AbilityMixinAvatarCombat", "AbilityMixinAvatarCombat"),
+        ("AbilityMixinUiInteract", "AbilityMixinUiInteract"),
+        ("AbilityMixinShootFromCamera", "AbilityMixinShootFromCamera"),
+        (
+            "AbilityMixinEraseBrickActivity",
+            "AbilityMixinEraseBrickActivity",
+        ),
+        ("AbilityMixinBreakout", "AbilityMixinBreakout"),
+        ("AbilityMixinDamageLoanSync", "AbilityMixinDamageLoan"),
+        ("AbilityMixinBroadcastGv", "AbilityMixinBroadcastGv"),
+        ("AbilityMixinReceiveGv", "AbilityMixinReceiveGv"),
+        (
+            "AbilityMixinEnergyCrystalTarget",
+            "AbilityMixinEnergyCrystalTarget",
+        ),
+    ];
+
+    let (client_to_normal_combat_invocation, normal_to_client_combat_invocation) =
+        impl_invocation_conversion(
+            &normal_file,
+            &client_file,
+            &client_mod,
+            quote!(CombatTypeArgument),
+            quote!(combat_data),
+            &combat_invoke_names,
+        );
+
+    let (client_to_normal_ability_invocation, normal_to_client_ability_invocation) =
+        impl_invocation_conversion(
+            &normal_file,
+            &client_file,
+            &client_mod,
+            quote!(AbilityInvokeArgument),
+            quote!(ability_data),
+            &ability_invoke_names,
+        );
 
     let conversion_fn = quote! {
+        use crate::packet::ProtocolConversionError;
+        use crate::Protobuf;
+
         #[allow(unused, warnings)] // disable lints for generated code
         pub fn client_to_normal(cmd_id: u16, body: &[u8]) -> Result<(u16, Box<[u8]>), ProtocolConversionError> {
             use crate::packet::{read_cmd_id, NetPacket};
@@ -345,6 +635,30 @@ fn impl_proto_conversion(
                 not_found => Err(ProtocolConversionError::NotFoundCombatArgument(arg_type)),
             }
         }
+
+        #[allow(unused, warnings)] // disable lints for generated code
+        pub fn combat_invocation_normal_to_client(arg_type: crate::normal::CombatTypeArgument, combat_data: &[u8]) -> Result<Box<[u8]>, ProtocolConversionError> {
+            match arg_type {
+                #normal_to_client_combat_invocation
+                not_found => Err(ProtocolConversionError::NotFoundCombatArgument(arg_type)),
+            }
+        }
+
+        #[allow(unused, warnings)] // disable lints for generated code
+        pub fn ability_invocation_client_to_normal(arg_type: crate::normal::AbilityInvokeArgument, ability_data: &[u8]) -> Result<Box<[u8]>, ProtocolConversionError> {
+            match arg_type {
+                #client_to_normal_ability_invocation
+                not_found => Err(ProtocolConversionError::NotFoundAbilityArgument(arg_type)),
+            }
+        }
+
+        #[allow(unused, warnings)] // disable lints for generated code
+        pub fn ability_invocation_normal_to_client(arg_type: crate::normal::AbilityInvokeArgument, ability_data: &[u8]) -> Result<Box<[u8]>, ProtocolConversionError> {
+            match arg_type {
+                #normal_to_client_ability_invocation
+                not_found => Err(ProtocolConversionError::NotFoundAbilityArgument(arg_type)),
+            }
+        }
     };
 
     let client_from_impls_ast = syn::parse2(client_from_impls.into_token_stream()).unwrap();
@@ -358,6 +672,59 @@ fn impl_proto_conversion(
     Ok(())
 }
 
+// Generates match arms converting invocation data for every (message, argument type) pair
+// that has the message defined in both protos
+fn impl_invocation_conversion(
+    normal_file: &syn::File,
+    client_file: &syn::File,
+    client_mod: &TokenStream,
+    arg_enum: TokenStream,
+    data_ident: TokenStream,
+    invoke_names: &[(&str, &str)],
+) -> (TokenStream, TokenStream) {
+    let has_struct = |file: &syn::File, name: &str| {
+        file.items.iter().any(|i| {
+            if let Item::Struct(s) = i {
+                s.ident == name
+            } else {
+                false
+            }
+        })
+    };
+
+    let mut client_to_normal = quote! {};
+    let mut normal_to_client = quote! {};
+
+    for (message, enum_case) in invoke_names.iter().copied() {
+        if !has_struct(client_file, message) || !has_struct(normal_file, message) {
+            continue;
+        }
+
+        let ident = Ident::new(message, Span::call_site());
+        let case_ident = Ident::new(enum_case, Span::call_site());
+
+        client_to_normal.extend(quote! {
+            crate::normal::#arg_enum::#case_ident => {
+                let proto = #client_mod::#ident::decode(#data_ident)?;
+                let proto: crate::normal::#ident = proto.into();
+
+                Ok(proto.encode_to_vec().into_boxed_slice())
+            }
+        });
+
+        normal_to_client.extend(quote! {
+            crate::normal::#arg_enum::#case_ident => {
+                let proto = crate::normal::#ident::decode(#data_ident)?;
+                let proto: #client_mod::#ident = proto.into();
+
+                Ok(proto.encode_to_vec().into_boxed_slice())
+            }
+        });
+    }
+
+    (client_to_normal, normal_to_client)
+}
+
 fn implement_cmd_id(path: &Path) -> std::io::Result<()> {
     let file = File::open(path)?;
     let reader = BufReader::new(file);
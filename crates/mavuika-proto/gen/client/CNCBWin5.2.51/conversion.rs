@@ -0,0 +1,1252 @@
+use crate::packet::ProtocolConversionError;
+use crate::Protobuf;
+#[allow(unused, warnings)]
+pub fn client_to_normal(
+    cmd_id: u16,
+    body: &[u8],
+) -> Result<(u16, Box<[u8]>), ProtocolConversionError> {
+    use crate::packet::{read_cmd_id, NetPacket};
+    use crate::{Protobuf, CmdID};
+    match cmd_id {
+        crate::client::v_cncbwin5_2_51::MarkMapRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::MarkMapRsp::decode(body)?;
+            let proto: crate::normal::MarkMapRsp = proto.into();
+            Ok((
+                crate::normal::MarkMapRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::SetUpAvatarTeamRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::SetUpAvatarTeamRsp::decode(
+                body,
+            )?;
+            let proto: crate::normal::SetUpAvatarTeamRsp = proto.into();
+            Ok((
+                crate::normal::SetUpAvatarTeamRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::GetPlayerTokenRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::GetPlayerTokenRsp::decode(body)?;
+            let proto: crate::normal::GetPlayerTokenRsp = proto.into();
+            Ok((
+                crate::normal::GetPlayerTokenRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::MarkMapReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::MarkMapReq::decode(body)?;
+            let proto: crate::normal::MarkMapReq = proto.into();
+            Ok((
+                crate::normal::MarkMapReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::GetSceneAreaReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::GetSceneAreaReq::decode(body)?;
+            let proto: crate::normal::GetSceneAreaReq = proto.into();
+            Ok((
+                crate::normal::GetSceneAreaReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarWearFlycloakRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarWearFlycloakRsp::decode(
+                body,
+            )?;
+            let proto: crate::normal::AvatarWearFlycloakRsp = proto.into();
+            Ok((
+                crate::normal::AvatarWearFlycloakRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::SceneTeamUpdateNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::SceneTeamUpdateNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::SceneTeamUpdateNotify = proto.into();
+            Ok((
+                crate::normal::SceneTeamUpdateNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::UnionCmdNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::UnionCmdNotify::decode(body)?;
+            let proto: crate::normal::UnionCmdNotify = proto.into();
+            Ok((
+                crate::normal::UnionCmdNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::GetScenePointRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::GetScenePointRsp::decode(body)?;
+            let proto: crate::normal::GetScenePointRsp = proto.into();
+            Ok((
+                crate::normal::GetScenePointRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::SceneInitFinishRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::SceneInitFinishRsp::decode(
+                body,
+            )?;
+            let proto: crate::normal::SceneInitFinishRsp = proto.into();
+            Ok((
+                crate::normal::SceneInitFinishRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarFightPropUpdateNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarFightPropUpdateNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::AvatarFightPropUpdateNotify = proto.into();
+            Ok((
+                crate::normal::AvatarFightPropUpdateNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PostEnterSceneReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PostEnterSceneReq::decode(body)?;
+            let proto: crate::normal::PostEnterSceneReq = proto.into();
+            Ok((
+                crate::normal::PostEnterSceneReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::SceneInitFinishReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::SceneInitFinishReq::decode(
+                body,
+            )?;
+            let proto: crate::normal::SceneInitFinishReq = proto.into();
+            Ok((
+                crate::normal::SceneInitFinishReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PlayerLoginRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PlayerLoginRsp::decode(body)?;
+            let proto: crate::normal::PlayerLoginRsp = proto.into();
+            Ok((
+                crate::normal::PlayerLoginRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::LifeStateChangeNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::LifeStateChangeNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::LifeStateChangeNotify = proto.into();
+            Ok((
+                crate::normal::LifeStateChangeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::ChangeAvatarRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::ChangeAvatarRsp::decode(body)?;
+            let proto: crate::normal::ChangeAvatarRsp = proto.into();
+            Ok((
+                crate::normal::ChangeAvatarRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::SceneEntityAppearNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::SceneEntityAppearNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::SceneEntityAppearNotify = proto.into();
+            Ok((
+                crate::normal::SceneEntityAppearNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PostEnterSceneRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PostEnterSceneRsp::decode(body)?;
+            let proto: crate::normal::PostEnterSceneRsp = proto.into();
+            Ok((
+                crate::normal::PostEnterSceneRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarChangeCostumeRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarChangeCostumeRsp::decode(
+                body,
+            )?;
+            let proto: crate::normal::AvatarChangeCostumeRsp = proto.into();
+            Ok((
+                crate::normal::AvatarChangeCostumeRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::WearEquipReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::WearEquipReq::decode(body)?;
+            let proto: crate::normal::WearEquipReq = proto.into();
+            Ok((
+                crate::normal::WearEquipReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PlayerEnterSceneInfoNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PlayerEnterSceneInfoNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::PlayerEnterSceneInfoNotify = proto.into();
+            Ok((
+                crate::normal::PlayerEnterSceneInfoNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::EvtAvatarLockChairRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::EvtAvatarLockChairRsp::decode(
+                body,
+            )?;
+            let proto: crate::normal::EvtAvatarLockChairRsp = proto.into();
+            Ok((
+                crate::normal::EvtAvatarLockChairRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PlayerEnterSceneNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PlayerEnterSceneNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::PlayerEnterSceneNotify = proto.into();
+            Ok((
+                crate::normal::PlayerEnterSceneNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::CombatInvocationsNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::CombatInvocationsNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::CombatInvocationsNotify = proto.into();
+            Ok((
+                crate::normal::CombatInvocationsNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::ServerTimeNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::ServerTimeNotify::decode(body)?;
+            let proto: crate::normal::ServerTimeNotify = proto.into();
+            Ok((
+                crate::normal::ServerTimeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::EvtBeingHitNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::EvtBeingHitNotify::decode(body)?;
+            let proto: crate::normal::EvtBeingHitNotify = proto.into();
+            Ok((
+                crate::normal::EvtBeingHitNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::SceneTimeNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::SceneTimeNotify::decode(body)?;
+            let proto: crate::normal::SceneTimeNotify = proto.into();
+            Ok((
+                crate::normal::SceneTimeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PlayerStoreNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PlayerStoreNotify::decode(body)?;
+            let proto: crate::normal::PlayerStoreNotify = proto.into();
+            Ok((
+                crate::normal::PlayerStoreNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PlayerLoginReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PlayerLoginReq::decode(body)?;
+            let proto: crate::normal::PlayerLoginReq = proto.into();
+            Ok((
+                crate::normal::PlayerLoginReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::ClientSetGameTimeRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::ClientSetGameTimeRsp::decode(
+                body,
+            )?;
+            let proto: crate::normal::ClientSetGameTimeRsp = proto.into();
+            Ok((
+                crate::normal::ClientSetGameTimeRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::EvtAvatarLockChairReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::EvtAvatarLockChairReq::decode(
+                body,
+            )?;
+            let proto: crate::normal::EvtAvatarLockChairReq = proto.into();
+            Ok((
+                crate::normal::EvtAvatarLockChairReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::EnterSceneReadyRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::EnterSceneReadyRsp::decode(
+                body,
+            )?;
+            let proto: crate::normal::EnterSceneReadyRsp = proto.into();
+            Ok((
+                crate::normal::EnterSceneReadyRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::GetScenePointReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::GetScenePointReq::decode(body)?;
+            let proto: crate::normal::GetScenePointReq = proto.into();
+            Ok((
+                crate::normal::GetScenePointReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::EnterSceneDoneRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::EnterSceneDoneRsp::decode(body)?;
+            let proto: crate::normal::EnterSceneDoneRsp = proto.into();
+            Ok((
+                crate::normal::EnterSceneDoneRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarFlycloakChangeNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarFlycloakChangeNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::AvatarFlycloakChangeNotify = proto.into();
+            Ok((
+                crate::normal::AvatarFlycloakChangeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarChangeTraceEffectRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarChangeTraceEffectRsp::decode(
+                body,
+            )?;
+            let proto: crate::normal::AvatarChangeTraceEffectRsp = proto.into();
+            Ok((
+                crate::normal::AvatarChangeTraceEffectRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::EnterScenePeerNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::EnterScenePeerNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::EnterScenePeerNotify = proto.into();
+            Ok((
+                crate::normal::EnterScenePeerNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::EntityFightPropUpdateNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::EntityFightPropUpdateNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::EntityFightPropUpdateNotify = proto.into();
+            Ok((
+                crate::normal::EntityFightPropUpdateNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarDataNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarDataNotify::decode(body)?;
+            let proto: crate::normal::AvatarDataNotify = proto.into();
+            Ok((
+                crate::normal::AvatarDataNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PlayerDataNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PlayerDataNotify::decode(body)?;
+            let proto: crate::normal::PlayerDataNotify = proto.into();
+            Ok((
+                crate::normal::PlayerDataNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarEquipChangeNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarEquipChangeNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::AvatarEquipChangeNotify = proto.into();
+            Ok((
+                crate::normal::AvatarEquipChangeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PingReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PingReq::decode(body)?;
+            let proto: crate::normal::PingReq = proto.into();
+            Ok((
+                crate::normal::PingReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::OpenStateUpdateNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::OpenStateUpdateNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::OpenStateUpdateNotify = proto.into();
+            Ok((
+                crate::normal::OpenStateUpdateNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::GetPlayerTokenReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::GetPlayerTokenReq::decode(body)?;
+            let proto: crate::normal::GetPlayerTokenReq = proto.into();
+            Ok((
+                crate::normal::GetPlayerTokenReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarWearFlycloakReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarWearFlycloakReq::decode(
+                body,
+            )?;
+            let proto: crate::normal::AvatarWearFlycloakReq = proto.into();
+            Ok((
+                crate::normal::AvatarWearFlycloakReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PathfindingEnterSceneRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PathfindingEnterSceneRsp::decode(
+                body,
+            )?;
+            let proto: crate::normal::PathfindingEnterSceneRsp = proto.into();
+            Ok((
+                crate::normal::PathfindingEnterSceneRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarChangeTraceEffectNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarChangeTraceEffectNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::AvatarChangeTraceEffectNotify = proto.into();
+            Ok((
+                crate::normal::AvatarChangeTraceEffectNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PlayerGameTimeNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PlayerGameTimeNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::PlayerGameTimeNotify = proto.into();
+            Ok((
+                crate::normal::PlayerGameTimeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::ClientSetGameTimeReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::ClientSetGameTimeReq::decode(
+                body,
+            )?;
+            let proto: crate::normal::ClientSetGameTimeReq = proto.into();
+            Ok((
+                crate::normal::ClientSetGameTimeReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PathfindingEnterSceneReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PathfindingEnterSceneReq::decode(
+                body,
+            )?;
+            let proto: crate::normal::PathfindingEnterSceneReq = proto.into();
+            Ok((
+                crate::normal::PathfindingEnterSceneReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PlayerNormalLuaShellNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PlayerNormalLuaShellNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::PlayerNormalLuaShellNotify = proto.into();
+            Ok((
+                crate::normal::PlayerNormalLuaShellNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarChangeCostumeNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarChangeCostumeNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::AvatarChangeCostumeNotify = proto.into();
+            Ok((
+                crate::normal::AvatarChangeCostumeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::EvtAvatarStandUpNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::EvtAvatarStandUpNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::EvtAvatarStandUpNotify = proto.into();
+            Ok((
+                crate::normal::EvtAvatarStandUpNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::EnterSceneReadyReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::EnterSceneReadyReq::decode(
+                body,
+            )?;
+            let proto: crate::normal::EnterSceneReadyReq = proto.into();
+            Ok((
+                crate::normal::EnterSceneReadyReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::ChangeAvatarReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::ChangeAvatarReq::decode(body)?;
+            let proto: crate::normal::ChangeAvatarReq = proto.into();
+            Ok((
+                crate::normal::ChangeAvatarReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::QueryPathReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::QueryPathReq::decode(body)?;
+            let proto: crate::normal::QueryPathReq = proto.into();
+            Ok((
+                crate::normal::QueryPathReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::GetSceneAreaRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::GetSceneAreaRsp::decode(body)?;
+            let proto: crate::normal::GetSceneAreaRsp = proto.into();
+            Ok((
+                crate::normal::GetSceneAreaRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarChangeCostumeReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarChangeCostumeReq::decode(
+                body,
+            )?;
+            let proto: crate::normal::AvatarChangeCostumeReq = proto.into();
+            Ok((
+                crate::normal::AvatarChangeCostumeReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarChangeTraceEffectReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarChangeTraceEffectReq::decode(
+                body,
+            )?;
+            let proto: crate::normal::AvatarChangeTraceEffectReq = proto.into();
+            Ok((
+                crate::normal::AvatarChangeTraceEffectReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::SetUpAvatarTeamReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::SetUpAvatarTeamReq::decode(
+                body,
+            )?;
+            let proto: crate::normal::SetUpAvatarTeamReq = proto.into();
+            Ok((
+                crate::normal::SetUpAvatarTeamReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::QueryPathRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::QueryPathRsp::decode(body)?;
+            let proto: crate::normal::QueryPathRsp = proto.into();
+            Ok((
+                crate::normal::QueryPathRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::AvatarTeamUpdateNotify::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::AvatarTeamUpdateNotify::decode(
+                body,
+            )?;
+            let proto: crate::normal::AvatarTeamUpdateNotify = proto.into();
+            Ok((
+                crate::normal::AvatarTeamUpdateNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::PingRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::PingRsp::decode(body)?;
+            let proto: crate::normal::PingRsp = proto.into();
+            Ok((
+                crate::normal::PingRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::EnterSceneDoneReq::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::EnterSceneDoneReq::decode(body)?;
+            let proto: crate::normal::EnterSceneDoneReq = proto.into();
+            Ok((
+                crate::normal::EnterSceneDoneReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::client::v_cncbwin5_2_51::WearEquipRsp::CMD_ID => {
+            let proto = crate::client::v_cncbwin5_2_51::WearEquipRsp::decode(body)?;
+            let proto: crate::normal::WearEquipRsp = proto.into();
+            Ok((
+                crate::normal::WearEquipRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        not_found => Err(ProtocolConversionError::NotFound(not_found)),
+    }
+}
+#[allow(unused, warnings)]
+pub fn normal_to_client(
+    cmd_id: u16,
+    body: &[u8],
+) -> Result<(u16, Box<[u8]>), ProtocolConversionError> {
+    use crate::packet::{read_cmd_id, NetPacket};
+    use crate::{Protobuf, CmdID};
+    match cmd_id {
+        crate::normal::MarkMapRsp::CMD_ID => {
+            let proto = crate::normal::MarkMapRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::MarkMapRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::MarkMapRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::SetUpAvatarTeamRsp::CMD_ID => {
+            let proto = crate::normal::SetUpAvatarTeamRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::SetUpAvatarTeamRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::SetUpAvatarTeamRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::GetPlayerTokenRsp::CMD_ID => {
+            let proto = crate::normal::GetPlayerTokenRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::GetPlayerTokenRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::GetPlayerTokenRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::MarkMapReq::CMD_ID => {
+            let proto = crate::normal::MarkMapReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::MarkMapReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::MarkMapReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::GetSceneAreaReq::CMD_ID => {
+            let proto = crate::normal::GetSceneAreaReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::GetSceneAreaReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::GetSceneAreaReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarWearFlycloakRsp::CMD_ID => {
+            let proto = crate::normal::AvatarWearFlycloakRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarWearFlycloakRsp = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarWearFlycloakRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::SceneTeamUpdateNotify::CMD_ID => {
+            let proto = crate::normal::SceneTeamUpdateNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::SceneTeamUpdateNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::SceneTeamUpdateNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::UnionCmdNotify::CMD_ID => {
+            let proto = crate::normal::UnionCmdNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::UnionCmdNotify = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::UnionCmdNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::GetScenePointRsp::CMD_ID => {
+            let proto = crate::normal::GetScenePointRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::GetScenePointRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::GetScenePointRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::SceneInitFinishRsp::CMD_ID => {
+            let proto = crate::normal::SceneInitFinishRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::SceneInitFinishRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::SceneInitFinishRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarFightPropUpdateNotify::CMD_ID => {
+            let proto = crate::normal::AvatarFightPropUpdateNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarFightPropUpdateNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarFightPropUpdateNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PostEnterSceneReq::CMD_ID => {
+            let proto = crate::normal::PostEnterSceneReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PostEnterSceneReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PostEnterSceneReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::SceneInitFinishReq::CMD_ID => {
+            let proto = crate::normal::SceneInitFinishReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::SceneInitFinishReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::SceneInitFinishReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PlayerLoginRsp::CMD_ID => {
+            let proto = crate::normal::PlayerLoginRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PlayerLoginRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PlayerLoginRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::LifeStateChangeNotify::CMD_ID => {
+            let proto = crate::normal::LifeStateChangeNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::LifeStateChangeNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::LifeStateChangeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::ChangeAvatarRsp::CMD_ID => {
+            let proto = crate::normal::ChangeAvatarRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::ChangeAvatarRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::ChangeAvatarRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::SceneEntityAppearNotify::CMD_ID => {
+            let proto = crate::normal::SceneEntityAppearNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::SceneEntityAppearNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::SceneEntityAppearNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PostEnterSceneRsp::CMD_ID => {
+            let proto = crate::normal::PostEnterSceneRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PostEnterSceneRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PostEnterSceneRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarChangeCostumeRsp::CMD_ID => {
+            let proto = crate::normal::AvatarChangeCostumeRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarChangeCostumeRsp = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarChangeCostumeRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::WearEquipReq::CMD_ID => {
+            let proto = crate::normal::WearEquipReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::WearEquipReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::WearEquipReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PlayerEnterSceneInfoNotify::CMD_ID => {
+            let proto = crate::normal::PlayerEnterSceneInfoNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PlayerEnterSceneInfoNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PlayerEnterSceneInfoNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::EvtAvatarLockChairRsp::CMD_ID => {
+            let proto = crate::normal::EvtAvatarLockChairRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::EvtAvatarLockChairRsp = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::EvtAvatarLockChairRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PlayerEnterSceneNotify::CMD_ID => {
+            let proto = crate::normal::PlayerEnterSceneNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PlayerEnterSceneNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PlayerEnterSceneNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::CombatInvocationsNotify::CMD_ID => {
+            let proto = crate::normal::CombatInvocationsNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::CombatInvocationsNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::CombatInvocationsNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::ServerTimeNotify::CMD_ID => {
+            let proto = crate::normal::ServerTimeNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::ServerTimeNotify = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::ServerTimeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::EvtBeingHitNotify::CMD_ID => {
+            let proto = crate::normal::EvtBeingHitNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::EvtBeingHitNotify = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::EvtBeingHitNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::SceneTimeNotify::CMD_ID => {
+            let proto = crate::normal::SceneTimeNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::SceneTimeNotify = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::SceneTimeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PlayerStoreNotify::CMD_ID => {
+            let proto = crate::normal::PlayerStoreNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PlayerStoreNotify = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PlayerStoreNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PlayerLoginReq::CMD_ID => {
+            let proto = crate::normal::PlayerLoginReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PlayerLoginReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PlayerLoginReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::ClientSetGameTimeRsp::CMD_ID => {
+            let proto = crate::normal::ClientSetGameTimeRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::ClientSetGameTimeRsp = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::ClientSetGameTimeRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::EvtAvatarLockChairReq::CMD_ID => {
+            let proto = crate::normal::EvtAvatarLockChairReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::EvtAvatarLockChairReq = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::EvtAvatarLockChairReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::EnterSceneReadyRsp::CMD_ID => {
+            let proto = crate::normal::EnterSceneReadyRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::EnterSceneReadyRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::EnterSceneReadyRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::GetScenePointReq::CMD_ID => {
+            let proto = crate::normal::GetScenePointReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::GetScenePointReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::GetScenePointReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::EnterSceneDoneRsp::CMD_ID => {
+            let proto = crate::normal::EnterSceneDoneRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::EnterSceneDoneRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::EnterSceneDoneRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarFlycloakChangeNotify::CMD_ID => {
+            let proto = crate::normal::AvatarFlycloakChangeNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarFlycloakChangeNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarFlycloakChangeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarChangeTraceEffectRsp::CMD_ID => {
+            let proto = crate::normal::AvatarChangeTraceEffectRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarChangeTraceEffectRsp = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarChangeTraceEffectRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::EnterScenePeerNotify::CMD_ID => {
+            let proto = crate::normal::EnterScenePeerNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::EnterScenePeerNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::EnterScenePeerNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::EntityFightPropUpdateNotify::CMD_ID => {
+            let proto = crate::normal::EntityFightPropUpdateNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::EntityFightPropUpdateNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::EntityFightPropUpdateNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarDataNotify::CMD_ID => {
+            let proto = crate::normal::AvatarDataNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarDataNotify = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarDataNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PlayerDataNotify::CMD_ID => {
+            let proto = crate::normal::PlayerDataNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PlayerDataNotify = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PlayerDataNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarEquipChangeNotify::CMD_ID => {
+            let proto = crate::normal::AvatarEquipChangeNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarEquipChangeNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarEquipChangeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PingReq::CMD_ID => {
+            let proto = crate::normal::PingReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PingReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PingReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::OpenStateUpdateNotify::CMD_ID => {
+            let proto = crate::normal::OpenStateUpdateNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::OpenStateUpdateNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::OpenStateUpdateNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::GetPlayerTokenReq::CMD_ID => {
+            let proto = crate::normal::GetPlayerTokenReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::GetPlayerTokenReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::GetPlayerTokenReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarWearFlycloakReq::CMD_ID => {
+            let proto = crate::normal::AvatarWearFlycloakReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarWearFlycloakReq = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarWearFlycloakReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PathfindingEnterSceneRsp::CMD_ID => {
+            let proto = crate::normal::PathfindingEnterSceneRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PathfindingEnterSceneRsp = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PathfindingEnterSceneRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarChangeTraceEffectNotify::CMD_ID => {
+            let proto = crate::normal::AvatarChangeTraceEffectNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarChangeTraceEffectNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarChangeTraceEffectNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PlayerGameTimeNotify::CMD_ID => {
+            let proto = crate::normal::PlayerGameTimeNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PlayerGameTimeNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PlayerGameTimeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::ClientSetGameTimeReq::CMD_ID => {
+            let proto = crate::normal::ClientSetGameTimeReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::ClientSetGameTimeReq = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::ClientSetGameTimeReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PathfindingEnterSceneReq::CMD_ID => {
+            let proto = crate::normal::PathfindingEnterSceneReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PathfindingEnterSceneReq = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PathfindingEnterSceneReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PlayerNormalLuaShellNotify::CMD_ID => {
+            let proto = crate::normal::PlayerNormalLuaShellNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PlayerNormalLuaShellNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PlayerNormalLuaShellNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarChangeCostumeNotify::CMD_ID => {
+            let proto = crate::normal::AvatarChangeCostumeNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarChangeCostumeNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarChangeCostumeNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::EvtAvatarStandUpNotify::CMD_ID => {
+            let proto = crate::normal::EvtAvatarStandUpNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::EvtAvatarStandUpNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::EvtAvatarStandUpNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::EnterSceneReadyReq::CMD_ID => {
+            let proto = crate::normal::EnterSceneReadyReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::EnterSceneReadyReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::EnterSceneReadyReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::ChangeAvatarReq::CMD_ID => {
+            let proto = crate::normal::ChangeAvatarReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::ChangeAvatarReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::ChangeAvatarReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::QueryPathReq::CMD_ID => {
+            let proto = crate::normal::QueryPathReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::QueryPathReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::QueryPathReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::GetSceneAreaRsp::CMD_ID => {
+            let proto = crate::normal::GetSceneAreaRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::GetSceneAreaRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::GetSceneAreaRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarChangeCostumeReq::CMD_ID => {
+            let proto = crate::normal::AvatarChangeCostumeReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarChangeCostumeReq = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarChangeCostumeReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarChangeTraceEffectReq::CMD_ID => {
+            let proto = crate::normal::AvatarChangeTraceEffectReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarChangeTraceEffectReq = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarChangeTraceEffectReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::SetUpAvatarTeamReq::CMD_ID => {
+            let proto = crate::normal::SetUpAvatarTeamReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::SetUpAvatarTeamReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::SetUpAvatarTeamReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::QueryPathRsp::CMD_ID => {
+            let proto = crate::normal::QueryPathRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::QueryPathRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::QueryPathRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::AvatarTeamUpdateNotify::CMD_ID => {
+            let proto = crate::normal::AvatarTeamUpdateNotify::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::AvatarTeamUpdateNotify = proto
+                .into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::AvatarTeamUpdateNotify::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::PingRsp::CMD_ID => {
+            let proto = crate::normal::PingRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::PingRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::PingRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::EnterSceneDoneReq::CMD_ID => {
+            let proto = crate::normal::EnterSceneDoneReq::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::EnterSceneDoneReq = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::EnterSceneDoneReq::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        crate::normal::WearEquipRsp::CMD_ID => {
+            let proto = crate::normal::WearEquipRsp::decode(body)?;
+            let proto: crate::client::v_cncbwin5_2_51::WearEquipRsp = proto.into();
+            Ok((
+                crate::client::v_cncbwin5_2_51::WearEquipRsp::CMD_ID,
+                proto.encode_to_vec().into_boxed_slice(),
+            ))
+        }
+        not_found => Err(ProtocolConversionError::NotFound(not_found)),
+    }
+}
+#[allow(unused, warnings)]
+pub fn combat_invocation_client_to_normal(
+    arg_type: crate::normal::CombatTypeArgument,
+    combat_data: &[u8],
+) -> Result<Box<[u8]>, ProtocolConversionError> {
+    match arg_type {
+        crate::normal::CombatTypeArgument::EvtBeingHit => {
+            let proto = crate::client::v_cncbwin5_2_51::EvtBeingHitInfo::decode(
+                combat_data,
+            )?;
+            let proto: crate::normal::EvtBeingHitInfo = proto.into();
+            Ok(proto.encode_to_vec().into_boxed_slice())
+        }
+        crate::normal::CombatTypeArgument::EntityMove => {
+            let proto = crate::client::v_cncbwin5_2_51::EntityMoveInfo::decode(
+                combat_data,
+            )?;
+            let proto: crate::normal::EntityMoveInfo = proto.into();
+            Ok(proto.encode_to_vec().into_boxed_slice())
+        }
+        not_found => Err(ProtocolConversionError::NotFoundCombatArgument(arg_type)),
+    }
+}
+#[allow(unused, warnings)]
+pub fn combat_invocation_normal_to_client(
+    arg_type: crate::normal::CombatTypeArgument,
+    combat_data: &[u8],
+) -> Result<Box<[u8]>, ProtocolConversionError> {
+    match arg_type {
+        crate::normal::CombatTypeArgument::EvtBeingHit => {
+            let proto = crate::normal::EvtBeingHitInfo::decode(combat_data)?;
+            let proto: crate::client::v_cncbwin5_2_51::EvtBeingHitInfo = proto.into();
+            Ok(proto.encode_to_vec().into_boxed_slice())
+        }
+        crate::normal::CombatTypeArgument::EntityMove => {
+            let proto = crate::normal::EntityMoveInfo::decode(combat_data)?;
+            let proto: crate::client::v_cncbwin5_2_51::EntityMoveInfo = proto.into();
+            Ok(proto.encode_to_vec().into_boxed_slice())
+        }
+        not_found => Err(ProtocolConversionError::NotFoundCombatArgument(arg_type)),
+    }
+}
+#[allow(unused, warnings)]
+pub fn ability_invocation_client_to_normal(
+    arg_type: crate::normal::AbilityInvokeArgument,
+    ability_data: &[u8],
+) -> Result<Box<[u8]>, ProtocolConversionError> {
+    match arg_type {
+        crate::normal::AbilityInvokeArgument::AbilityMetaOverrideParam => {
+            let proto = crate::client::v_cncbwin5_2_51::AbilityScalarValueEntry::decode(
+                ability_data,
+            )?;
+            let proto: crate::normal::AbilityScalarValueEntry = proto.into();
+            Ok(proto.encode_to_vec().into_boxed_slice())
+        }
+        crate::normal::AbilityInvokeArgument::AbilityMetaGlobalFloatValue => {
+            let proto = crate::client::v_cncbwin5_2_51::AbilityScalarValueEntry::decode(
+                ability_data,
+            )?;
+            let proto: crate::normal::AbilityScalarValueEntry = proto.into();
+            Ok(proto.encode_to_vec().into_boxed_slice())
+        }
+        not_found => Err(ProtocolConversionError::NotFoundAbilityArgument(arg_type)),
+    }
+}
+#[allow(unused, warnings)]
+pub fn ability_invocation_normal_to_client(
+    arg_type: crate::normal::AbilityInvokeArgument,
+    ability_data: &[u8],
+) -> Result<Box<[u8]>, ProtocolConversionError> {
+    match arg_type {
+        crate::normal::AbilityInvokeArgument::AbilityMetaOverrideParam => {
+            let proto = crate::normal::AbilityScalarValueEntry::decode(ability_data)?;
+            let proto: crate::client::v_cncbwin5_2_51::AbilityScalarValueEntry = proto
+                .into();
+            Ok(proto.encode_to_vec().into_boxed_slice())
+        }
+        crate::normal::AbilityInvokeArgument::AbilityMetaGlobalFloatValue => {
+            let proto = crate::normal::AbilityScalarValueEntry::decode(ability_data)?;
+            let proto: crate::client::v_cncbwin5_2_51::AbilityScalarValueEntry = proto
+                .into();
+            Ok(proto.encode_to_vec().into_boxed_slice())
+        }
+        not_found => Err(ProtocolConversionError::NotFoundAbilityArgument(arg_type)),
+    }
+}
@@ -1720,6 +1720,7 @@ impl From<crate::normal::OpenStateUpdateNotify> for OpenStateUpdateNotify {
 impl From<crate::normal::GetPlayerTokenReq> for GetPlayerTokenReq {
     fn from(value: crate::normal::GetPlayerTokenReq) -> Self {
         Self {
+            token: value.account_token.into(),
             client_rand_key: value.client_rand_key.into(),
             account_uid: value.account_uid.into(),
             key_id: value.key_id.into(),
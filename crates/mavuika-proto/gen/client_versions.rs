@@ -0,0 +1,4 @@
+pub mod v_cncbwin5_2_51 {
+    include!("client/CNCBWin5.2.51/client.rs");
+    include!("client/CNCBWin5.2.51/normal_to_client.rs");
+}
@@ -1,5 +1,4 @@
 // disable lints on generated code
 #![allow(unused, warnings)]
 
-include!("../gen/client.rs");
-include!("../gen/normal_to_client.rs");
+include!("../gen/client_versions.rs");
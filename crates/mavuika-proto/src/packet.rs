@@ -30,6 +30,28 @@ pub enum ProtocolConversionError {
     NotFound(u16),
     #[error("normal proto for arg_type: {0:?} not found")]
     NotFoundCombatArgument(crate::normal::CombatTypeArgument),
+    #[error("normal proto for arg_type: {0:?} not found")]
+    NotFoundAbilityArgument(crate::normal::AbilityInvokeArgument),
+}
+
+pub type PacketConverter = fn(u16, &[u8]) -> Result<(u16, Box<[u8]>), ProtocolConversionError>;
+pub type InvocationConverter<Arg> = fn(Arg, &[u8]) -> Result<Box<[u8]>, ProtocolConversionError>;
+
+// Set of converters between normal proto and proto of specific client version
+pub struct ClientProtocol {
+    pub version: &'static str,
+    pub client_to_normal: PacketConverter,
+    pub normal_to_client: PacketConverter,
+    pub combat_invocation_client_to_normal: InvocationConverter<crate::normal::CombatTypeArgument>,
+    pub combat_invocation_normal_to_client: InvocationConverter<crate::normal::CombatTypeArgument>,
+    pub ability_invocation_client_to_normal:
+        InvocationConverter<crate::normal::AbilityInvokeArgument>,
+    pub ability_invocation_normal_to_client:
+        InvocationConverter<crate::normal::AbilityInvokeArgument>,
+}
+
+pub fn client_protocol(version: &str) -> Option<&'static ClientProtocol> {
+    CLIENT_PROTOCOLS.iter().find(|p| p.version == version)
 }
 
 const OVERHEAD: usize = 12;
@@ -0,0 +1,34 @@
+use mavuika_proto::{packet, CmdID, GetPlayerTokenReq, Protobuf};
+
+// Subset of client GetPlayerTokenReq, cmd_id and tags as in gen/client/CNCBWin5.2.51/client.rs
+const CLIENT_GET_PLAYER_TOKEN_REQ_CMD_ID: u16 = 9655;
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct ClientGetPlayerTokenReq {
+    #[prost(string, tag = "9")]
+    token: String,
+    #[prost(string, tag = "7")]
+    account_uid: String,
+    #[prost(uint32, tag = "1906")]
+    key_id: u32,
+}
+
+#[test]
+fn get_player_token_req_carries_combo_token() {
+    let request = ClientGetPlayerTokenReq {
+        token: String::from("combo-token"),
+        account_uid: String::from("1337"),
+        key_id: 5,
+    };
+
+    let protocol = packet::client_protocol("CNCBWin5.2.51").unwrap();
+    let (cmd_id, body) =
+        (protocol.client_to_normal)(CLIENT_GET_PLAYER_TOKEN_REQ_CMD_ID, &request.encode_to_vec())
+            .unwrap();
+    assert_eq!(cmd_id, GetPlayerTokenReq::CMD_ID);
+
+    let request = GetPlayerTokenReq::decode(body.as_ref()).unwrap();
+    assert_eq!(request.account_token, "combo-token");
+    assert_eq!(request.account_uid, "1337");
+    assert_eq!(request.key_id, 5);
+}
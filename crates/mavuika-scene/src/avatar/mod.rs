@@ -72,8 +72,8 @@ pub fn change_avatar(
                         .insert(Visible)
                         .insert(transform);
 
-                    out.send(
-                        message.sender_uid(),
+                    out.reply(
+                        message,
                         ChangeAvatarRsp {
                             cur_guid: request.guid,
                             skill_id: request.skill_id,
@@ -100,8 +100,8 @@ pub fn set_up_avatar_team(
                     .avatar_team_guid_list
                     .contains(&request.cur_avatar_guid)
             {
-                out.send(
-                    message.sender_uid(),
+                out.reply(
+                    message,
                     SetUpAvatarTeamRsp {
                         retcode: Retcode::RetFail.into(),
                         ..Default::default()
@@ -118,8 +118,8 @@ pub fn set_up_avatar_team(
                         request.avatar_team_guid_list
                     );
 
-                    out.send(
-                        message.sender_uid(),
+                    out.reply(
+                        message,
                         SetUpAvatarTeamRsp {
                             retcode: Retcode::RetFail.into(),
                             ..Default::default()
@@ -139,8 +139,8 @@ pub fn set_up_avatar_team(
                     cur_avatar_guid: request.cur_avatar_guid,
                 });
 
-                out.send(
-                    message.sender_uid(),
+                out.reply(
+                    message,
                     SetUpAvatarTeamRsp {
                         retcode: Retcode::RetSucc.into(),
                         team_id: request.team_id,
@@ -151,8 +151,8 @@ pub fn set_up_avatar_team(
             } else {
                 debug!("team_id {} doesn't exist", request.team_id);
 
-                out.send(
-                    message.sender_uid(),
+                out.reply(
+                    message,
                     SetUpAvatarTeamRsp {
                         retcode: Retcode::RetFail.into(),
                         ..Default::default()
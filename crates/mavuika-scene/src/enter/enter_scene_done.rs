@@ -9,7 +9,10 @@ use mavuika_proto::{EnterSceneDoneRsp, Retcode};
 use crate::common::PlayerSceneStates;
 
 #[derive(Event)]
-pub struct EnterSceneDoneEvent(pub u32);
+pub struct EnterSceneDoneEvent {
+    pub uid: u32,
+    pub client_sequence_id: u32,
+}
 
 pub fn on_enter_scene_done(
     mut commands: Commands,
@@ -17,7 +20,7 @@ pub fn on_enter_scene_done(
     avatars: Query<(Entity, AvatarQueryReadOnly), With<CurrentPlayerAvatarMarker>>,
 ) {
     for event in reader.read() {
-        let uid = event.0;
+        let uid = event.uid;
 
         let (cur_player_avatar, _) = avatars
             .iter()
@@ -34,10 +37,11 @@ pub fn enter_scene_done_send_rsp(
     message_output: Res<MessageOutput>,
 ) {
     for event in enter_scene_done_events.read() {
-        let uid = event.0;
+        let uid = event.uid;
 
-        message_output.send(
+        message_output.send_response(
             uid,
+            event.client_sequence_id,
             EnterSceneDoneRsp {
                 retcode: Retcode::RetSucc.into(),
                 enter_scene_token: player_scene_states.get(&uid).unwrap().enter_scene_token(),
@@ -4,7 +4,10 @@ use mavuika_message::output::MessageOutput;
 use crate::common::{CurrentSceneID, PlayerSceneStates, ScenePeerManager};
 
 #[derive(Event)]
-pub struct EnterSceneReadyEvent(pub u32);
+pub struct EnterSceneReadyEvent {
+    pub uid: u32,
+    pub client_sequence_id: u32,
+}
 
 pub fn on_enter_scene_ready(
     mut reader: EventReader<EnterSceneReadyEvent>,
@@ -14,7 +17,7 @@ pub fn on_enter_scene_ready(
     mut peer_manager: ResMut<ScenePeerManager>,
 ) {
     for event in reader.read() {
-        let uid = event.0;
+        let uid = event.uid;
         let enter_scene_token = player_scene_states.get(&uid).unwrap().enter_scene_token();
 
         let peer_id = peer_manager.get_or_add_peer(uid);
@@ -32,8 +35,9 @@ pub fn on_enter_scene_ready(
             },
         );
 
-        out.send(
+        out.send_response(
             uid,
+            event.client_sequence_id,
             mavuika_proto::EnterSceneReadyRsp {
                 enter_scene_token,
                 retcode: mavuika_proto::Retcode::RetSucc.into(),
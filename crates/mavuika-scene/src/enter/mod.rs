@@ -13,7 +13,10 @@ pub use scene_init_finish::scene_init_finish_send_rsp;
 
 use crate::common::PlayerSceneStates;
 
-use bevy_ecs::{prelude::*, system::SystemId};
+use bevy_ecs::{
+    prelude::*,
+    system::{SystemId, SystemParam},
+};
 use mavuika_message::{event::ClientMessageEvent, output::MessageOutput};
 use mavuika_proto::{
     CmdID, EnterSceneDoneReq, EnterSceneDoneRsp, EnterSceneReadyReq, EnterSceneReadyRsp,
@@ -28,13 +31,12 @@ macro_rules! check_token_and_get_next_state {
         match $pkt.cmd_id() {
             $(
                 paste!([<$ty Req>]::CMD_ID) => {
-                    let uid = $pkt.sender_uid();
                     let Some(req) = $pkt.decode::<paste!([<$ty Req>])>() else {
                         continue;
                     };
 
                     if req.enter_scene_token != $token {
-                        $out.send(uid, ::paste::paste!([<$ty Rsp>] {
+                        $out.reply($pkt, ::paste::paste!([<$ty Rsp>] {
                             retcode: Retcode::RetEnterSceneTokenInvalid.into(),
                             ..Default::default()
                         }));
@@ -89,16 +91,21 @@ impl FromWorld for EnterSceneStateSystems {
     }
 }
 
+#[derive(SystemParam)]
+pub struct EnterSceneEventWriters<'w> {
+    ready: EventWriter<'w, EnterSceneReadyEvent>,
+    init_finish: EventWriter<'w, SceneInitFinishEvent>,
+    done: EventWriter<'w, EnterSceneDoneEvent>,
+    post: EventWriter<'w, PostEnterSceneEvent>,
+}
+
 pub fn handle_enter_scene_state_change(
     mut messages: EventReader<ClientMessageEvent>,
     mut commands: Commands,
     systems: Res<EnterSceneStateSystems>,
     output: Res<MessageOutput>,
     mut player_scene_states: ResMut<PlayerSceneStates>,
-    mut ready_events: EventWriter<EnterSceneReadyEvent>,
-    mut init_finish_events: EventWriter<SceneInitFinishEvent>,
-    mut done_events: EventWriter<EnterSceneDoneEvent>,
-    mut post_events: EventWriter<PostEnterSceneEvent>,
+    mut events: EnterSceneEventWriters,
 ) {
     for msg in messages.read() {
         let token = player_scene_states
@@ -123,18 +130,31 @@ pub fn handle_enter_scene_state_change(
             );
 
             let uid = msg.sender_uid();
+            let client_sequence_id = msg.head().client_sequence_id;
             match next_enter_state {
                 EnterSceneState::Ready => {
-                    ready_events.send(EnterSceneReadyEvent(uid));
+                    events.ready.send(EnterSceneReadyEvent {
+                        uid,
+                        client_sequence_id,
+                    });
                 }
                 EnterSceneState::InitFinish => {
-                    init_finish_events.send(SceneInitFinishEvent(uid));
+                    events.init_finish.send(SceneInitFinishEvent {
+                        uid,
+                        client_sequence_id,
+                    });
                 }
                 EnterSceneState::Done => {
-                    done_events.send(EnterSceneDoneEvent(uid));
+                    events.done.send(EnterSceneDoneEvent {
+                        uid,
+                        client_sequence_id,
+                    });
                 }
                 EnterSceneState::Post => {
-                    post_events.send(PostEnterSceneEvent(uid));
+                    events.post.send(PostEnterSceneEvent {
+                        uid,
+                        client_sequence_id,
+                    });
                 }
             }
 
@@ -6,7 +6,10 @@ use mavuika_proto::{Retcode, SceneInitFinishRsp};
 use crate::{common::PlayerSceneStates, player_join_team::PlayerJoinTeamEvent};
 
 #[derive(Event)]
-pub struct SceneInitFinishEvent(pub u32);
+pub struct SceneInitFinishEvent {
+    pub uid: u32,
+    pub client_sequence_id: u32,
+}
 
 pub fn on_scene_init_finish(
     mut reader: EventReader<SceneInitFinishEvent>,
@@ -14,7 +17,7 @@ pub fn on_scene_init_finish(
     mut join_team_events: EventWriter<PlayerJoinTeamEvent>,
 ) {
     for event in reader.read() {
-        let uid = event.0;
+        let uid = event.uid;
         let player_info = players.get(uid);
 
         let appear_avatar_guid = player_info
@@ -47,10 +50,11 @@ pub fn scene_init_finish_send_rsp(
     message_output: Res<MessageOutput>,
 ) {
     for event in scene_init_finish_events.read() {
-        let uid = event.0;
+        let uid = event.uid;
 
-        message_output.send(
+        message_output.send_response(
             uid,
+            event.client_sequence_id,
             SceneInitFinishRsp {
                 retcode: Retcode::RetSucc.into(),
                 enter_scene_token: player_scene_states.get(&uid).unwrap().enter_scene_token(),
@@ -28,7 +28,7 @@ pub fn sync_enter_info(
     avatars: Query<(AvatarQueryReadOnly, Option<&CurrentPlayerAvatarMarker>)>,
     weapons: Query<WeaponQueryReadOnly>,
 ) {
-    for SceneInitFinishEvent(uid) in scene_init_events.read() {
+    for SceneInitFinishEvent { uid, .. } in scene_init_events.read() {
         let uid = *uid;
 
         let (team_entity_id, team_ability) = team_entity_query.single();
@@ -89,7 +89,7 @@ pub fn sync_play_team_entity(
     cur_scene_id: Res<CurrentSceneID>,
     peer_mgr: Res<ScenePeerManager>,
 ) {
-    for SceneInitFinishEvent(uid) in scene_init_events.read() {
+    for SceneInitFinishEvent { uid, .. } in scene_init_events.read() {
         message_output.send(
             *uid,
             SyncScenePlayTeamEntityNotify {
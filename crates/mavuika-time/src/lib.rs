@@ -62,7 +62,7 @@ pub fn client_set_game_time(
                 });
             }
 
-            message_output.send(uid, rsp);
+            message_output.reply(message, rsp);
         }
     }
 }
@@ -75,7 +75,7 @@ pub fn sync_scene_time_on_scene_init(
 ) {
     use mavuika_proto::{PlayerGameTimeNotify, SceneTimeNotify};
 
-    for SceneInitFinishEvent(uid) in events.read() {
+    for SceneInitFinishEvent { uid, .. } in events.read() {
         message_output.send(
             *uid,
             ServerTimeNotify {